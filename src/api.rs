@@ -1,26 +1,82 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
+use bytes::Bytes;
+use futures::{Stream, StreamExt, stream};
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::Certificate;
 use reqwest::{
-    Client, ClientBuilder, Method, RequestBuilder, StatusCode,
-    header::{HeaderMap, HeaderValue},
+    Client, ClientBuilder, Method, Request, RequestBuilder, Response, StatusCode,
+    header::{
+        ACCEPT, ACCEPT_LANGUAGE, HeaderMap, HeaderName, HeaderValue, IF_NONE_MATCH, USER_AGENT,
+    },
 };
 
+use serde::{Deserialize, de::DeserializeOwned};
+use time::{OffsetDateTime, UtcDateTime};
+use url::Url;
+// `std::time::Instant` panics in the browser.
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
 use crate::{
-    VERSION,
-    err::{self, delete, get, post, validate},
-    nexus_joiner,
+    GameScope, VERSION,
+    cache::{Lookup, ResponseCache},
+    err::{self, ApiBuildError, delete, get, post, validate},
+    json_stream,
+    nxm::{NxmLink, NxmParams},
     request::{
-        CategoryName, Changelog, Endorsements, GameId, GameMod, ModFile, ModFiles, ModId,
-        ModUpdated, RateLimiting, TimePeriod, TrackedModsRaw, Validate,
+        BASE_URL, CategoryName, Changelog, DownloadLink, EndorsementInfo, Endorsements, FileStatus,
+        GameDomain, GameId, GameMod, HasEndorsed, LastResponseMeta, Limited, Md5Result, ModFile,
+        ModFiles, ModId, ModSummary, ModUpdated, PreviewFileRoot, RateLimiting, ScanVerdict,
+        TimePeriod, TrackedModsRaw, Validate, VirusScan, build_url_on,
     },
+    throttle::Throttle,
 };
 
+/// When the games list was fetched, and the list itself.
+type CachedGames = (Instant, Arc<[GameId]>);
+
 /// Top level API handler.
 ///
 /// All network calls are handled through here.
+///
+/// Cloning is cheap and clones share the same connection pool and rate limit state, so prefer
+/// handing out clones over wrapping this in an [`Arc`].
+#[derive(Clone)]
 pub struct Api {
-    key: String,
+    /// Sent with every request. Not set as the client's default headers so that a client
+    /// supplied through [`Api::with_client`] works too.
+    headers: HeaderMap,
+    /// Applied per request rather than on the client, for the same reason as `headers` and
+    /// because WASM clients don't support a client-wide timeout.
+    timeout: Option<Duration>,
+    /// Where the API lives, normally [`BASE_URL`].
+    base_url: Url,
+    default_game: Option<GameDomain>,
+    throttle: Option<Arc<Throttle>>,
     client: Client,
+    /// Rate limits as of the most recent response.
+    rate_limits: Arc<Mutex<Option<RateLimiting>>>,
+    last_response: Arc<Mutex<Option<LastResponseMeta>>>,
+    /// See [`Api::games_cached`].
+    games: Arc<Mutex<Option<CachedGames>>>,
+    /// Without the `cache` feature this only holds responses that can be revalidated with an
+    /// `ETag`, see [`Api::send_conditional`].
+    cache: Arc<ResponseCache>,
+}
+
+impl std::fmt::Debug for Api {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Api")
+            .field("key", &"***")
+            .finish_non_exhaustive()
+    }
 }
 
 impl Api {
@@ -31,43 +87,674 @@ impl Api {
     /// # Examples
     ///
     /// ```no_run
-    /// # use cyclone::Api;
+    /// # use cyclone_mod::Api;
     /// # tokio_test::block_on(async {
     /// let api = Api::new("here is my custom key");
     /// assert!(api.validate().await.is_ok());
     /// # })
     /// ```
+    ///
+    /// # Panics
+    /// If `key` cannot be sent as a header. Use [`Api::builder`] to handle that case.
     pub fn new<S: Into<String>>(key: S) -> Self {
-        let key = key.into();
-        let client = ClientBuilder::new().default_headers({
-            let mut h = HeaderMap::new();
-            h.insert("apikey", key.parse().unwrap());
-            h.insert("accept", HeaderValue::from_static("application/json"));
-            h
-        });
-        Self {
-            key,
-            client: client.build().expect("oops"),
+        Self::builder(key).build().expect("oops")
+    }
+
+    /// Configure a wrapper before creating it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cyclone_mod::{Api, err::ApiBuildError};
+    /// # fn main() -> Result<(), ApiBuildError> {
+    /// let api = Api::builder("here is my custom key").build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder<S: Into<String>>(key: S) -> ApiBuilder {
+        ApiBuilder {
+            key: key.into(),
+            client: None,
+            app_info: None,
+            language: None,
+            timeout: None,
+            base_url: None,
+            default_game: None,
+            requests_per_second: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            accept_invalid_certs: false,
+            #[cfg(not(target_arch = "wasm32"))]
+            root_certificates: vec![],
+            // Same as reqwest's own defaults.
+            #[cfg(not(target_arch = "wasm32"))]
+            pool_max_idle_per_host: usize::MAX,
+            #[cfg(not(target_arch = "wasm32"))]
+            pool_idle_timeout: Some(Duration::from_secs(90)),
+            #[cfg(not(target_arch = "wasm32"))]
+            http2_prior_knowledge: false,
+            #[cfg(not(target_arch = "wasm32"))]
+            http1_only: false,
+            #[cfg(feature = "cache")]
+            cache_ttl: Duration::from_secs(60),
+        }
+    }
+
+    /// Create a new wrapper that sends its requests through an existing [`Client`].
+    ///
+    /// Useful to share one connection pool, proxy, and TLS setup with the rest of an application.
+    /// The API key is attached to each request rather than to `client` itself.
+    ///
+    /// # Panics
+    /// If `key` cannot be sent as a header.
+    pub fn with_client<S: Into<String>>(key: S, client: Client) -> Self {
+        ApiBuilder {
+            client: Some(client),
+            ..Self::builder(key)
         }
+        .build()
+        .expect("oops")
     }
 
-    pub(crate) fn key(&self) -> &str {
-        &self.key
+    /// The version of the Nexus API this crate targets, such as `v1`.
+    pub const fn api_version() -> &'static str {
+        VERSION
     }
 
-    fn build(
+    fn build<'a>(
         &self,
         method: Method,
         ver: &str,
         slugs: &[&str],
-        params: &[(&'static str, &str)],
-    ) -> RequestBuilder {
-        self.client
-            .request(method, nexus_joiner!(ver, slugs))
-            .query(params)
+        params: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Result<RequestBuilder, url::ParseError> {
+        let request = self
+            .client
+            .request(method, build_url_on(&self.base_url, ver, slugs)?)
+            .headers(self.headers.clone())
+            .query(&params.into_iter().collect::<Vec<_>>());
+
+        Ok(match self.timeout {
+            Some(timeout) => request.timeout(timeout),
+            None => request,
+        })
+    }
+
+    /// Send a request built with [`Api::build`].
+    async fn send(&self, request: RequestBuilder) -> Result<Response, reqwest::Error> {
+        self.send_with(request, cfg!(feature = "cache")).await
+    }
+
+    /// Like [`Api::send`], but always remember the response so a repeat request can be answered
+    /// by a `304 Not Modified`.
+    ///
+    /// Meant for large, rarely changing responses.
+    async fn send_conditional(&self, request: RequestBuilder) -> Result<Response, reqwest::Error> {
+        self.send_with(request, true).await
     }
 
-    // TODO: Add rate limiting checking.
+    async fn send_with(
+        &self,
+        request: RequestBuilder,
+        cached: bool,
+    ) -> Result<Response, reqwest::Error> {
+        let mut request = request.build()?;
+
+        if request.method() != Method::GET {
+            let response = self.execute(request).await;
+            // Whatever was tracked, endorsed, and so on may have changed.
+            self.cache.clear();
+            return response;
+        }
+        // Only the API itself is cached. Other hosts don't count against the rate limit anyway.
+        if !cached || request.url().origin() != self.base_url.origin() {
+            return self.execute(request).await;
+        }
+
+        let (method, url) = (request.method().clone(), request.url().clone());
+        // Kept in case the entry is gone by the time the `304 Not Modified` arrives.
+        let mut unconditional = None;
+
+        match self.cache.lookup(&method, &url) {
+            Lookup::Fresh(response) => return Ok(response),
+            Lookup::Stale(etag) => {
+                unconditional = request.try_clone();
+                request.headers_mut().insert(IF_NONE_MATCH, etag);
+            }
+            Lookup::Miss => {}
+        }
+
+        let response = self.execute(request).await?;
+        let response = self
+            .cache
+            .store(method.clone(), url.clone(), response)
+            .await?;
+
+        match unconditional {
+            // The cache was cleared while the request was in flight, so there is nothing left
+            // for the `304` to refer to.
+            Some(request) if response.status() == StatusCode::NOT_MODIFIED => {
+                let response = self.execute(request).await?;
+                self.cache.store(method, url, response).await
+            }
+            _ => Ok(response),
+        }
+    }
+
+    /// Put `request` on the wire, recording the response's metadata.
+    async fn execute(&self, request: Request) -> Result<Response, reqwest::Error> {
+        if let Some(throttle) = &self.throttle {
+            throttle.acquire().await;
+        }
+
+        let start = Instant::now();
+        let response = self.dispatch(request).await;
+
+        if let Ok(response) = &response {
+            *self.last_response.lock().unwrap() = Some(LastResponseMeta::new(
+                response.status(),
+                response.headers(),
+                start.elapsed(),
+            ));
+            if let Some(limits) = RateLimiting::from_headers(response.headers()) {
+                *self.rate_limits.lock().unwrap() = Some(limits);
+            }
+        }
+
+        response
+    }
+
+    /// Fetch `url` from a host other than the Nexus API, such as a CDN.
+    ///
+    /// The API key and `Accept` header are left off, and the response is never cached, throttled,
+    /// or taken into account by [`Api::last_response_meta`] and [`Api::rate_limits`].
+    async fn get_external(&self, url: Url) -> Result<Response, reqwest::Error> {
+        let mut headers = self.headers.clone();
+        headers.remove("apikey");
+        headers.remove(ACCEPT);
+
+        let request = self.client.get(url).headers(headers);
+        let request = match self.timeout {
+            Some(timeout) => request.timeout(timeout),
+            None => request,
+        };
+
+        self.dispatch(request.build()?).await
+    }
+
+    /// Every network call funnels through here, so that anything wanting to observe the
+    /// request/response pair only needs to hook in once.
+    async fn dispatch(&self, request: Request) -> Result<Response, reqwest::Error> {
+        #[cfg(feature = "tracing")]
+        {
+            use tracing::Instrument;
+
+            let start = Instant::now();
+
+            let span = tracing::debug_span!(
+                "request",
+                method = %request.method(),
+                url = %request.url(),
+                status = tracing::field::Empty,
+                elapsed = tracing::field::Empty,
+            );
+            async {
+                tracing::trace!(headers = ?redacted(request.headers()), "sending request");
+                let response = self.client.execute(request).await;
+                let span = tracing::Span::current();
+                span.record("elapsed", tracing::field::debug(start.elapsed()));
+                match &response {
+                    Ok(response) => {
+                        span.record("status", response.status().as_u16());
+                        tracing::debug!(headers = ?redacted(response.headers()), "received response");
+                    }
+                    Err(e) => tracing::debug!(error = %e, "request failed"),
+                }
+                response
+            }
+            .instrument(span)
+            .await
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        self.client.execute(request).await
+    }
+
+    /// Drop every cached response.
+    ///
+    /// Safe to call while requests are in flight. One that was revalidating a dropped response is
+    /// sent again without `If-None-Match`.
+    pub fn clear_cache(&self) {
+        self.cache.clear();
+    }
+
+    /// Make requests about `game` without passing it every time.
+    pub fn scope(&self, game: impl Into<GameDomain>) -> GameScope<'_> {
+        GameScope::new(self, game.into())
+    }
+
+    /// [`Api::scope`] for the game set with [`ApiBuilder::default_game`], or [`None`] if there
+    /// isn't one.
+    pub fn default_scope(&self) -> Option<GameScope<'_>> {
+        self.default_game
+            .clone()
+            .map(|game| GameScope::new(self, game))
+    }
+}
+
+/// Configuration for an [`Api`].
+///
+/// Obtained through [`Api::builder`].
+#[derive(Clone)]
+pub struct ApiBuilder {
+    key: String,
+    client: Option<Client>,
+    /// Application name and version.
+    app_info: Option<(String, String)>,
+    /// `Accept-Language` value.
+    language: Option<String>,
+    timeout: Option<Duration>,
+    base_url: Option<String>,
+    default_game: Option<String>,
+    requests_per_second: Option<f64>,
+    #[cfg(not(target_arch = "wasm32"))]
+    accept_invalid_certs: bool,
+    #[cfg(not(target_arch = "wasm32"))]
+    root_certificates: Vec<Certificate>,
+    #[cfg(not(target_arch = "wasm32"))]
+    pool_max_idle_per_host: usize,
+    #[cfg(not(target_arch = "wasm32"))]
+    pool_idle_timeout: Option<Duration>,
+    #[cfg(not(target_arch = "wasm32"))]
+    http2_prior_knowledge: bool,
+    #[cfg(not(target_arch = "wasm32"))]
+    http1_only: bool,
+    #[cfg(feature = "cache")]
+    cache_ttl: Duration,
+}
+
+impl std::fmt::Debug for ApiBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ApiBuilder")
+            .field("key", &"***")
+            .finish_non_exhaustive()
+    }
+}
+
+impl ApiBuilder {
+    /// How long a `GET` response may be reused before it is refetched (or revalidated, if Nexus
+    /// sent an `ETag`). Defaults to one minute.
+    ///
+    /// A shorter `max-age` sent by Nexus takes precedence. Anything that changes state on Nexus,
+    /// such as [`Api::track_mod`] or [`Api::endorse`], drops every cached response so the change
+    /// shows up straight away.
+    #[cfg(feature = "cache")]
+    pub const fn cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = ttl;
+        self
+    }
+
+    /// Identify your application to Nexus in the `User-Agent`, as they ask API consumers to.
+    ///
+    /// This produces `{name}/{version} (cyclone/{crate version})`. Without it, only
+    /// `cyclone/{crate version}` is sent.
+    pub fn app_info(mut self, name: &str, version: &str) -> Self {
+        self.app_info = Some((name.to_string(), version.to_string()));
+        self
+    }
+
+    /// Ask Nexus for content in `language`, such as `de` or `pt-BR`, where it has translations.
+    ///
+    /// Sent as the `Accept-Language` header. Unset by default, which gets English.
+    pub fn language(mut self, language: &str) -> Self {
+        self.language = Some(language.to_string());
+        self
+    }
+
+    /// Give up on a request that hasn't completed within `timeout`.
+    ///
+    /// Requests that time out fail with a `Timeout` error rather than the generic `Reqwest` one.
+    /// There is no timeout by default.
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Send requests to another instance of the API, such as a staging server, instead of
+    /// `https://api.nexusmods.com`.
+    ///
+    /// The version and `.json` suffix are added as usual, after any path `base` already has.
+    /// [`Api::build`](`ApiBuilder::build`) fails if `base` isn't an absolute URL with a host.
+    ///
+    /// ```
+    /// # use cyclone_mod::{Api, err::ApiBuildError};
+    /// assert!(Api::builder("key").base_url("https://staging.example.com").build().is_ok());
+    /// assert!(matches!(
+    ///     Api::builder("key").base_url("/v1").build(),
+    ///     Err(ApiBuildError::InvalidBaseUrl(_)),
+    /// ));
+    /// ```
+    pub fn base_url(mut self, base: &str) -> Self {
+        self.base_url = Some(base.to_string());
+        self
+    }
+
+    /// The game [`Api::default_scope`] is bound to, for applications that only ever deal with
+    /// one game.
+    ///
+    /// [`Api::build`](`ApiBuilder::build`) fails if this isn't a valid [`GameDomain`].
+    pub fn default_game(mut self, game: &str) -> Self {
+        self.default_game = Some(game.to_string());
+        self
+    }
+
+    /// Send at most `rate` requests per second, shared across every clone of the [`Api`].
+    ///
+    /// Requests over the limit wait their turn rather than failing. Short bursts of up to `rate`
+    /// requests are let through at once. There is no limit by default.
+    pub const fn requests_per_second(mut self, rate: f64) -> Self {
+        self.requests_per_second = Some(rate);
+        self
+    }
+
+    /// Trust `certificate` in addition to the system's root certificates, such as the CA of a
+    /// corporate proxy.
+    ///
+    /// Ignored if the [`Api`] is built around an existing client with [`Api::with_client`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Accept any TLS certificate, even expired ones or ones for the wrong host.
+    ///
+    /// # Warning
+    /// This lets anyone between you and Nexus read and alter every request, including the API
+    /// key. Prefer [`ApiBuilder::add_root_certificate`], and only reach for this as a last
+    /// resort.
+    ///
+    /// Ignored if the [`Api`] is built around an existing client with [`Api::with_client`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub const fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.accept_invalid_certs = accept;
+        self
+    }
+
+    /// Keep at most `max` idle connections open to Nexus. Unlimited by default.
+    ///
+    /// Ignored if the [`Api`] is built around an existing client with [`Api::with_client`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub const fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = max;
+        self
+    }
+
+    /// Close idle connections after `timeout`, or never with [`None`]. Defaults to 90 seconds.
+    ///
+    /// Ignored if the [`Api`] is built around an existing client with [`Api::with_client`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub const fn pool_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.pool_idle_timeout = timeout;
+        self
+    }
+
+    /// Speak HTTP/2 straight away instead of negotiating it, so that many requests share one
+    /// connection.
+    ///
+    /// Requests fail if the server doesn't support HTTP/2. By default the protocol is negotiated
+    /// during the TLS handshake.
+    ///
+    /// Ignored if the [`Api`] is built around an existing client with [`Api::with_client`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub const fn http2_prior_knowledge(mut self, enabled: bool) -> Self {
+        self.http2_prior_knowledge = enabled;
+        self
+    }
+
+    /// Never use HTTP/2, even if the server offers it. Takes precedence over
+    /// [`ApiBuilder::http2_prior_knowledge`].
+    ///
+    /// Ignored if the [`Api`] is built around an existing client with [`Api::with_client`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub const fn http1_only(mut self, enabled: bool) -> Self {
+        self.http1_only = enabled;
+        self
+    }
+
+    pub fn build(self) -> Result<Api, ApiBuildError> {
+        let throttle = match self.requests_per_second {
+            Some(rate) if rate.is_finite() && rate > 0.0 => Some(Arc::new(Throttle::new(rate))),
+            Some(rate) => return Err(ApiBuildError::InvalidRate(rate)),
+            None => None,
+        };
+        let base_url = match &self.base_url {
+            Some(base) => Url::parse(base)
+                .ok()
+                .filter(|url| url.has_host() && !url.cannot_be_a_base())
+                .ok_or_else(|| ApiBuildError::InvalidBaseUrl(base.clone()))?,
+            None => Url::parse(BASE_URL).expect("default base URL is valid"),
+        };
+        let mut key: HeaderValue = self.key.parse()?;
+        key.set_sensitive(true);
+        let crate_agent = concat!("cyclone/", env!("CARGO_PKG_VERSION"));
+        let user_agent = match &self.app_info {
+            Some((name, version)) => format!("{name}/{version} ({crate_agent})")
+                .parse()
+                .map_err(ApiBuildError::InvalidAppInfo)?,
+            None => HeaderValue::from_static(crate_agent),
+        };
+        let mut headers = HeaderMap::from_iter([
+            (HeaderName::from_static("apikey"), key),
+            (ACCEPT, HeaderValue::from_static("application/json")),
+            (USER_AGENT, user_agent),
+        ]);
+        if let Some(language) = &self.language {
+            headers.insert(
+                ACCEPT_LANGUAGE,
+                language.parse().map_err(ApiBuildError::InvalidLanguage)?,
+            );
+        }
+
+        let client = match self.client {
+            Some(client) => client,
+            #[cfg(not(target_arch = "wasm32"))]
+            None => {
+                let mut client = self
+                    .root_certificates
+                    .into_iter()
+                    .fold(ClientBuilder::new(), ClientBuilder::add_root_certificate)
+                    .danger_accept_invalid_certs(self.accept_invalid_certs)
+                    .pool_max_idle_per_host(self.pool_max_idle_per_host)
+                    .pool_idle_timeout(self.pool_idle_timeout);
+                if self.http2_prior_knowledge {
+                    client = client.http2_prior_knowledge();
+                }
+                if self.http1_only {
+                    client = client.http1_only();
+                }
+                client.build()?
+            }
+            #[cfg(target_arch = "wasm32")]
+            None => ClientBuilder::new().build()?,
+        };
+
+        Ok(Api {
+            headers,
+            timeout: self.timeout,
+            base_url,
+            default_game: self
+                .default_game
+                .as_deref()
+                .map(GameDomain::new)
+                .transpose()?,
+            throttle,
+            client,
+            rate_limits: Arc::default(),
+            last_response: Arc::default(),
+            games: Arc::default(),
+            #[cfg(feature = "cache")]
+            cache: Arc::new(ResponseCache::new(self.cache_ttl)),
+            #[cfg(not(feature = "cache"))]
+            cache: Arc::new(ResponseCache::new(Duration::ZERO)),
+        })
+    }
+}
+
+/// Endpoints this crate doesn't wrap yet.
+impl Api {
+    /// Make an authenticated `GET` request to any endpoint and return the raw JSON.
+    ///
+    /// `slugs` are joined like every other request, so `("v1", &["games", "skyrim", "mods",
+    /// "latest_added"])` requests `v1/games/skyrim/mods/latest_added.json`. Any status other than
+    /// success is returned as [`get::GameModError::Reqwest`].
+    ///
+    /// # Stability
+    /// This is an escape hatch until a typed method exists. It may change or go away without
+    /// a major version bump, so prefer the typed methods where they exist.
+    pub async fn raw_get(
+        &self,
+        ver: &str,
+        slugs: &[&str],
+        params: &[(&str, &str)],
+    ) -> Result<serde_json::Value, get::GameModError> {
+        let request = self.build(Method::GET, ver, slugs, params.iter().copied())?;
+        let response = self.send(request).await?.error_for_status()?;
+
+        response.json().await.map_err(get::GameModError::from)
+    }
+
+    /// Make an authenticated `GET` request and return both the parsed response and the exact
+    /// bytes Nexus sent.
+    ///
+    /// Useful for mirroring Nexus data locally: store the bytes and deserialize them again later,
+    /// possibly with a newer version of this crate. `slugs` and `params` work like
+    /// [`Api::raw_get`], and any status other than success is returned as
+    /// [`get::GameModError::Reqwest`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cyclone_mod::{Api, err::get::GameModError, request::GameMod};
+    /// # async fn run(api: Api) -> Result<(), GameModError> {
+    /// let (game_mod, bytes) = api
+    ///     .get_with_bytes::<GameMod>("v1", &["games", "skyrim", "mods", "3863"], &[])
+    ///     .await?;
+    /// std::fs::write("3863.json", &bytes)?;
+    ///
+    /// // Later, offline.
+    /// let replayed = GameMod::from_json(&std::fs::read_to_string("3863.json")?)?;
+    /// assert_eq!(replayed.name(), game_mod.name());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_with_bytes<T: DeserializeOwned>(
+        &self,
+        ver: &str,
+        slugs: &[&str],
+        params: &[(&str, &str)],
+    ) -> Result<(T, Bytes), get::GameModError> {
+        let request = self.build(Method::GET, ver, slugs, params.iter().copied())?;
+        let bytes = self
+            .send(request)
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?;
+
+        Ok((serde_json::from_slice(&bytes)?, bytes))
+    }
+}
+
+/// Rate limiting and response metadata.
+impl Api {
+    /// Rate limits as reported by the most recent response, if a request has been made yet.
+    pub fn rate_limits(&self) -> Option<RateLimiting> {
+        *self.rate_limits.lock().unwrap()
+    }
+
+    /// Can `requests` more requests be made without running into either the hourly or daily
+    /// limit?
+    ///
+    /// # Notes
+    /// This only consults the last seen [`RateLimiting`], so if no request has been made yet this
+    /// optimistically returns `true`.
+    pub fn can_afford(&self, requests: u16) -> bool {
+        self.rate_limits().is_none_or(|limits| {
+            limits.remaining(Limited::Hourly) >= requests
+                && limits.remaining(Limited::Daily) >= requests
+        })
+    }
+
+    /// The limit set with [`ApiBuilder::requests_per_second`], if any.
+    pub fn requests_per_second(&self) -> Option<f64> {
+        self.throttle.as_ref().map(|throttle| throttle.rate())
+    }
+
+    /// Status, headers, and timing of the most recent response from Nexus, if a request has been
+    /// made yet.
+    ///
+    /// Responses served from the cache are not counted.
+    pub fn last_response_meta(&self) -> Option<LastResponseMeta> {
+        self.last_response.lock().unwrap().clone()
+    }
+}
+
+/// Parse the body of a `200 OK` from a `GET` endpoint, or turn any other status into an error
+/// with [`get_error`].
+async fn get_json<T: DeserializeOwned>(response: Response) -> Result<T, get::GameModError> {
+    match response.status() {
+        StatusCode::OK => Ok(response.json().await?),
+        _ => get_error(response).await,
+    }
+}
+
+/// The error for a status a `GET` endpoint doesn't handle itself.
+///
+/// Nexus explains a `401`, `403` or `404` in the body, such as a download needing a premium
+/// membership or a mod hidden by the adult content filter. Anything else is [`unexpected`].
+async fn get_error<T>(response: Response) -> Result<T, get::GameModError> {
+    let status = response.status();
+    if !matches!(
+        status,
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN | StatusCode::NOT_FOUND
+    ) {
+        return unexpected(response);
+    }
+
+    let err::InvalidAPIKeyError { message } = response.json().await?;
+    Err(match status {
+        StatusCode::UNAUTHORIZED => err::InvalidAPIKeyError { message }.into(),
+        _ if err::AdultContentBlocked::matches(&message) => {
+            err::AdultContentBlocked { message, status }.into()
+        }
+        StatusCode::FORBIDDEN => get::GameModError::Forbidden { message },
+        _ => get::GameModError::NotFound { message },
+    })
+}
+
+/// The fallback for a status an endpoint doesn't document.
+///
+/// Error statuses become a `Reqwest` error that keeps the status, so `kind` and `is_transient`
+/// can tell a `429` or `503` apart from a genuine failure.
+fn unexpected<T, E>(response: Response) -> Result<T, E>
+where
+    E: From<reqwest::Error> + From<err::UnexpectedStatus>,
+{
+    let status = response.status();
+    match response.error_for_status() {
+        Err(e) => Err(e.into()),
+        Ok(_) => Err(err::UnexpectedStatus(status).into()),
+    }
+}
+
+/// Copy of `headers` with the API key scrubbed, suitable for logging.
+#[cfg(feature = "tracing")]
+fn redacted(headers: &HeaderMap) -> HeaderMap {
+    let mut headers = headers.clone();
+    if headers.contains_key("apikey") {
+        headers.insert("apikey", HeaderValue::from_static("***"));
+    }
+    headers
 }
 
 /// User related methods.
@@ -85,7 +772,7 @@ impl Api {
     /// # Examples
     ///
     /// ```no_run
-    /// # use cyclone::{Api, err::validate::ValidateError};
+    /// # use cyclone_mod::{Api, err::validate::ValidateError};
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), ValidateError> {
     /// let api = Api::new("...");
@@ -95,25 +782,41 @@ impl Api {
     /// # }
     /// ```
     pub async fn validate(&self) -> Result<Validate, validate::ValidateError> {
-        let response = self
-            .build(Method::GET, VERSION, &["users", "validate"], &[])
-            .send()
-            .await?;
+        let request = self.build(Method::GET, VERSION, &["users", "validate"], [])?;
+        let response = self.send(request).await?;
 
         match response.status() {
-            StatusCode::OK => response
-                .json()
-                .await
-                .map_err(validate::ValidateError::Reqwest),
+            StatusCode::OK => {
+                let validate: Validate = response.json().await?;
+                #[cfg(feature = "tracing")]
+                if let Some(sent) = self.headers.get("apikey").and_then(|key| key.to_str().ok())
+                    && !validate.matches_key(sent)
+                {
+                    tracing::warn!(
+                        "the API key Nexus accepted differs from the one sent, store `Validate::key` instead"
+                    );
+                }
+                Ok(validate)
+            }
             StatusCode::UNAUTHORIZED => Err(validate::ValidateError::InvalidAPIKey(
                 response.json().await?,
             )),
-            StatusCode::UNPROCESSABLE_ENTITY => {
-                unimplemented!(
-                    "I have not yet encountered this return code but it is listed as a valid return code"
-                );
-            }
-            _ => unreachable!("The only three documented return codes are 200, 404 (401), and 422"),
+            _ => unexpected(response),
+        }
+    }
+
+    /// Is the API key accepted by Nexus?
+    ///
+    /// Like [`Api::validate`], but an invalid key is `Ok(false)` rather than an error, and the
+    /// user details are not parsed.
+    pub async fn is_key_valid(&self) -> Result<bool, validate::ValidateError> {
+        let request = self.build(Method::GET, VERSION, &["users", "validate"], [])?;
+        let response = self.send(request).await?;
+
+        match response.status() {
+            StatusCode::OK => Ok(true),
+            StatusCode::UNAUTHORIZED => Ok(false),
+            _ => unexpected(response),
         }
     }
 
@@ -122,25 +825,15 @@ impl Api {
     /// # Notes
     /// Consider converting to [`TrackedMods`](`crate::request::TrackedMods`).
     pub async fn tracked_mods(&self) -> Result<TrackedModsRaw, validate::ValidateError> {
-        let response = self
-            .build(Method::GET, VERSION, &["user", "tracked_mods"], &[])
-            .send()
-            .await?;
+        let request = self.build(Method::GET, VERSION, &["user", "tracked_mods"], [])?;
+        let response = self.send(request).await?;
 
         match response.status() {
-            StatusCode::OK => response
-                .json()
-                .await
-                .map_err(validate::ValidateError::Reqwest),
+            StatusCode::OK => response.json().await.map_err(validate::ValidateError::from),
             StatusCode::UNAUTHORIZED => Err(validate::ValidateError::InvalidAPIKey(
                 response.json().await?,
             )),
-            StatusCode::UNPROCESSABLE_ENTITY => {
-                unimplemented!(
-                    "I have not yet encountered this return code but it is listed as a valid return code"
-                );
-            }
-            _ => unreachable!("The only three documented return codes are 200, 404 (401), and 422"),
+            _ => unexpected(response),
         }
     }
 
@@ -151,12 +844,11 @@ impl Api {
         id: T,
     ) -> Result<post::PostModStatus, post::TrackModError> {
         let id = id.into();
-        let response = self
-            .build(Method::POST, VERSION, &["user", "tracked_mods"], &[])
+        let request = self
+            .build(Method::POST, VERSION, &["user", "tracked_mods"], [])?
             .query(&[("domain_name", game)])
-            .form(&HashMap::from([("mod_id", id)]))
-            .send()
-            .await?;
+            .form(&HashMap::from([("mod_id", id)]));
+        let response = self.send(request).await?;
 
         match response.status() {
             StatusCode::OK => Ok(post::PostModStatus::AlreadyTracking(ModId::from_u64(id))),
@@ -167,58 +859,90 @@ impl Api {
                 Err(response.json::<err::InvalidAPIKeyError>().await?.into())
             }
             StatusCode::NOT_FOUND => Err(response.json::<err::ModNotFoundError>().await?.into()),
-            _ => unreachable!("The only four documented return codes are 200, 201, 404, and 401"),
+            _ => unexpected(response),
         }
     }
 
+    /// Track the mod an `nxm://` link points to, such as one dropped onto the application.
+    ///
+    /// Parse the link with [`NxmLink::parse`] first, which rejects malformed links before any
+    /// request is made.
+    pub async fn track_from_nxm(
+        &self,
+        link: &NxmLink,
+    ) -> Result<post::PostModStatus, post::TrackModError> {
+        self.track_mod(link.game(), link.mod_id()).await
+    }
+
     /// Untrack a mod.
     ///
+    /// Untracking a mod that isn't tracked is not an error, so this is safe to retry.
+    ///
     /// # Notes
     /// This function takes in a [`ModId`], not a `u64` because it is assumed that (unlike
-    /// [`Api::track_mod`]) the caller knows of a valid mod ID.
+    /// [`Api::track_mod`]) the caller knows of a valid mod ID. Nexus answers `404` both for
+    /// untracked and for invalid mods, so given a valid ID that means it wasn't tracked.
     pub async fn untrack_mod<T: Into<ModId>>(
         &self,
         game: &str,
         id: T,
-    ) -> Result<(), delete::DeleteModError> {
+    ) -> Result<delete::UntrackStatus, delete::DeleteModError> {
         let id = id.into();
-        let response = self
-            .build(Method::DELETE, VERSION, &["user", "tracked_mods"], &[])
+        let request = self
+            .build(Method::DELETE, VERSION, &["user", "tracked_mods"], [])?
             .query(&[("domain_name", game)])
-            .form(&HashMap::from([("mod_id", id)]))
-            .send()
-            .await?;
+            .form(&HashMap::from([("mod_id", id)]));
+        let response = self.send(request).await?;
 
         match response.status() {
-            StatusCode::OK => Ok(()),
-            StatusCode::NOT_FOUND => {
-                Err(response.json::<err::UntrackedOrInvalidMod>().await?.into())
-            }
-            _ => unreachable!("The only two documented return codes are 200 and 404"),
+            StatusCode::OK => Ok(delete::UntrackStatus::Removed),
+            StatusCode::NOT_FOUND => Ok(delete::UntrackStatus::WasNotTracked),
+            _ => unexpected(response),
         }
     }
 
+    /// Untrack many mods at once.
+    ///
+    /// At most `concurrency` requests are in flight at once. Each result is tagged with the mod it
+    /// belongs to and results are in the same order as `ids`.
+    pub async fn untrack_all(
+        &self,
+        game: &str,
+        ids: &[ModId],
+        concurrency: usize,
+    ) -> Vec<(ModId, Result<delete::UntrackStatus, delete::DeleteModError>)> {
+        stream::iter(ids)
+            .map(|id| async move { (*id, self.untrack_mod(game, *id).await) })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Download the user's avatar, as linked from [`Validate::url`].
+    ///
+    /// The avatar is served from a CDN rather than the API, so the API key isn't sent along.
+    ///
+    /// # Notes
+    /// The image format isn't guaranteed. Sniff it from the bytes rather than assuming one.
+    pub async fn avatar_bytes(&self, validate: &Validate) -> Result<Bytes, reqwest::Error> {
+        self.get_external(validate.url().clone())
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await
+    }
+
     /// Get a list of mods the user has endorsed.
     pub async fn endorsements(&self) -> Result<Endorsements, validate::ValidateError> {
-        let response = self
-            .build(Method::GET, VERSION, &["user", "endorsements"], &[])
-            .send()
-            .await?;
+        let request = self.build(Method::GET, VERSION, &["user", "endorsements"], [])?;
+        let response = self.send(request).await?;
 
         match response.status() {
-            StatusCode::OK => response
-                .json()
-                .await
-                .map_err(validate::ValidateError::Reqwest),
+            StatusCode::OK => response.json().await.map_err(validate::ValidateError::from),
             StatusCode::UNAUTHORIZED => Err(validate::ValidateError::InvalidAPIKey(
                 response.json().await?,
             )),
-            StatusCode::UNPROCESSABLE_ENTITY => {
-                unimplemented!(
-                    "I have not yet encountered this return code but it is listed as a valid return code"
-                );
-            }
-            _ => unreachable!("The only three documented return codes are 200, 404 (401), and 422"),
+            _ => unexpected(response),
         }
     }
 }
@@ -227,13 +951,13 @@ impl Api {
 ///
 /// - [x] `GET`  [`v1/games/{game_domain_name}/mods/updated`](`Api::updated_during`)
 /// - [x] `GET`  [`v1/games/{game_domain_name}/mods/{mod_id}/changelogs`](`Api::changelogs`)
-/// - [ ] `GET`  `v1/games/{game_domain_name}/mods/latest_added`
-/// - [ ] `GET`  `v1/games/{game_domain_name}/mods/latest_updated`
-/// - [ ] `GET`  `v1/games/{game_domain_name}/mods/trending`
+/// - [x] `GET`  [`v1/games/{game_domain_name}/mods/latest_added`](`Api::latest_added`)
+/// - [x] `GET`  [`v1/games/{game_domain_name}/mods/latest_updated`](`Api::latest_updated`)
+/// - [x] `GET`  [`v1/games/{game_domain_name}/mods/trending`](`Api::trending`)
 /// - [x] `GET`  [`v1/games/{game_domain_name}/mods/{id}`](`Api::mod_info`)
-/// - [ ] `GET`  `v1/games/{game_domain_name}/mods/md5_search/{md5_hash}`
-/// - [ ] `POST` `v1/games/{game_domain_name}/mods/{id}/endorse`
-/// - [ ] `POST` `v1/games/{game_domain_name}/mods/{id}/abstain`
+/// - [x] `GET`  [`v1/games/{game_domain_name}/mods/md5_search/{md5_hash}`](`Api::md5_search`)
+/// - [x] `POST` [`v1/games/{game_domain_name}/mods/{id}/endorse`](`Api::endorse`)
+/// - [x] `POST` [`v1/games/{game_domain_name}/mods/{id}/abstain`](`Api::abstain`)
 impl Api {
     /// Get a list of mods updated within a timeframe.
     pub async fn updated_during(
@@ -241,26 +965,154 @@ impl Api {
         game: &str,
         time: TimePeriod,
     ) -> Result<Vec<ModUpdated>, get::GameModError> {
-        let response = self
-            .build(
-                Method::GET,
-                VERSION,
-                &["games", game, "mods", "updated"],
-                &[("period", time.as_str())],
-            )
-            .send()
+        let request = self.build(
+            Method::GET,
+            VERSION,
+            &["games", game, "mods", "updated"],
+            [("period", time.as_str())],
+        )?;
+        let response = self.send(request).await?;
+
+        get_json(response).await
+    }
+
+    /// Get a list of mods with activity since a given point in time.
+    ///
+    /// # Notes
+    /// Nexus only understands [day, week, and month](`TimePeriod`) windows, so this fetches the
+    /// smallest window covering `since` and filters the results client side. Expect it to
+    /// over-fetch, and expect anything older than a month to be missing entirely.
+    pub async fn updated_since(
+        &self,
+        game: &str,
+        since: OffsetDateTime,
+    ) -> Result<Vec<ModUpdated>, get::GameModError> {
+        let since = since.to_utc();
+        let window = (UtcDateTime::now() - since)
+            .try_into()
+            .unwrap_or(Duration::ZERO);
+
+        let mut updated = self
+            .updated_during(game, TimePeriod::covering(window))
             .await?;
+        updated.retain(|m| m.last_activity().is_some_and(|activity| activity >= since));
+        Ok(updated)
+    }
 
-        match response.status() {
-            StatusCode::OK => response.json().await.map_err(get::GameModError::Reqwest),
-            StatusCode::NOT_FOUND => Err(response.json::<err::InvalidAPIKeyError>().await?.into()),
-            StatusCode::UNPROCESSABLE_ENTITY => {
-                unimplemented!(
-                    "I have not yet encountered this return code but it is listed as a valid return code"
-                );
-            }
-            _ => unreachable!("The only three documented return codes are 200, 404, and 422"),
+    /// Like [`Api::updated_since`], but fail instead of silently returning a partial list when
+    /// `since` is further back than Nexus keeps history for.
+    ///
+    /// # Notes
+    /// The endpoint only takes windows ending now, with no way to page further back, so a client
+    /// that has been offline for longer than a [`TimePeriod::Month`] can't catch up through it.
+    /// On [`get::GameModError::HistoryUnavailable`], fall back to checking each mod of interest
+    /// with [`Api::mod_info`].
+    pub async fn updated_since_checked(
+        &self,
+        game: &str,
+        since: UtcDateTime,
+    ) -> Result<Vec<ModUpdated>, get::GameModError> {
+        if UtcDateTime::now() - since > Into::<Duration>::into(TimePeriod::Month) {
+            return Err(get::GameModError::HistoryUnavailable { since });
         }
+
+        self.updated_since(game, since.into()).await
+    }
+
+    /// Get a list of mods updated within a timeframe, along with each mod's details.
+    ///
+    /// At most `concurrency` detail requests are in flight at once. Results are in the same order
+    /// as [`Api::updated_during`] returns them. If the initial listing fails, its error is the
+    /// only element returned.
+    pub async fn updated_with_details(
+        &self,
+        game: &str,
+        time: TimePeriod,
+        concurrency: usize,
+    ) -> Vec<Result<(ModUpdated, GameMod), get::GameModError>> {
+        self.updated_with(game, time, concurrency).await
+    }
+
+    /// Like [`Api::updated_with_details`], but only read a [`ModSummary`] of each mod.
+    pub async fn updated_with_summaries(
+        &self,
+        game: &str,
+        time: TimePeriod,
+        concurrency: usize,
+    ) -> Vec<Result<(ModUpdated, ModSummary), get::GameModError>> {
+        self.updated_with(game, time, concurrency).await
+    }
+
+    async fn updated_with<T: DeserializeOwned>(
+        &self,
+        game: &str,
+        time: TimePeriod,
+        concurrency: usize,
+    ) -> Vec<Result<(ModUpdated, T), get::GameModError>> {
+        let updated = match self.updated_during(game, time).await {
+            Ok(updated) => updated,
+            Err(e) => return vec![Err(e)],
+        };
+
+        stream::iter(updated)
+            .map(|m| async move {
+                self.mod_info_as(game, m.id(), true)
+                    .await
+                    .map(|info| (m, info))
+            })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// The 10 mods most recently added to `game`.
+    pub async fn latest_added(&self, game: &str) -> Result<Vec<GameMod>, get::GameModError> {
+        self.mod_list(game, "latest_added").await
+    }
+
+    /// Like [`Api::latest_added`], but only read a [`ModSummary`] of each mod.
+    pub async fn latest_added_summaries(
+        &self,
+        game: &str,
+    ) -> Result<Vec<ModSummary>, get::GameModError> {
+        self.mod_list(game, "latest_added").await
+    }
+
+    /// The 10 mods most recently updated for `game`.
+    pub async fn latest_updated(&self, game: &str) -> Result<Vec<GameMod>, get::GameModError> {
+        self.mod_list(game, "latest_updated").await
+    }
+
+    /// Like [`Api::latest_updated`], but only read a [`ModSummary`] of each mod.
+    pub async fn latest_updated_summaries(
+        &self,
+        game: &str,
+    ) -> Result<Vec<ModSummary>, get::GameModError> {
+        self.mod_list(game, "latest_updated").await
+    }
+
+    /// The 10 mods currently trending for `game`.
+    pub async fn trending(&self, game: &str) -> Result<Vec<GameMod>, get::GameModError> {
+        self.mod_list(game, "trending").await
+    }
+
+    /// Like [`Api::trending`], but only read a [`ModSummary`] of each mod.
+    pub async fn trending_summaries(
+        &self,
+        game: &str,
+    ) -> Result<Vec<ModSummary>, get::GameModError> {
+        self.mod_list(game, "trending").await
+    }
+
+    async fn mod_list<T: DeserializeOwned>(
+        &self,
+        game: &str,
+        list: &str,
+    ) -> Result<Vec<T>, get::GameModError> {
+        let request = self.build(Method::GET, VERSION, &["games", game, "mods", list], [])?;
+        let response = self.send(request).await?;
+
+        get_json(response).await
     }
 
     /// Get changelogs for a mod.
@@ -270,54 +1122,225 @@ impl Api {
         id: T,
     ) -> Result<Changelog, get::GameModError> {
         let id = id.into();
+        let request = self.build(
+            Method::GET,
+            VERSION,
+            &["games", game, "mods", id.to_string().as_str(), "changelogs"],
+            [],
+        )?;
+        let response = self.send(request).await?;
+
+        get_json(response).await
+    }
+
+    /// Get specific mod information.
+    pub async fn mod_info<T: Into<ModId>>(
+        &self,
+        game: &str,
+        id: T,
+    ) -> Result<GameMod, get::GameModError> {
+        self.mod_info_as(game, id.into(), true).await
+    }
+
+    /// Like [`Api::mod_info`], but only read a [`ModSummary`].
+    pub async fn mod_summary<T: Into<ModId>>(
+        &self,
+        game: &str,
+        id: T,
+    ) -> Result<ModSummary, get::GameModError> {
+        self.mod_info_as(game, id.into(), true).await
+    }
+
+    /// Fetch a mod, deserialized as `T`. `cached` is passed on to [`Api::send_with`].
+    async fn mod_info_as<T: DeserializeOwned>(
+        &self,
+        game: &str,
+        id: ModId,
+        cached: bool,
+    ) -> Result<T, get::GameModError> {
+        let request = self.build(
+            Method::GET,
+            VERSION,
+            &["games", game, "mods", id.to_string().as_str()],
+            [],
+        )?;
         let response = self
-            .build(
-                Method::GET,
-                VERSION,
-                &["games", game, "mods", id.to_string().as_str(), "changelogs"],
-                &[],
-            )
-            .send()
+            .send_with(request, cached && cfg!(feature = "cache"))
             .await?;
 
+        get_json(response).await
+    }
+
+    /// Whether the user has endorsed, abstained from, or not yet decided on a mod.
+    ///
+    /// Cheaper than [`Api::endorsements`] for checking a single mod, such as to confirm an
+    /// [`Api::endorse`] or [`Api::abstain`] went through. The answer is never cached.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cyclone_mod::{Api, request::{HasEndorsed, ModId}};
+    /// # async fn run(api: Api, id: ModId) -> Result<(), Box<dyn std::error::Error>> {
+    /// api.abstain("skyrim", id, None).await?;
+    /// assert_eq!(api.endorsement_state("skyrim", id).await?, HasEndorsed::Abstained);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn endorsement_state<T: Into<ModId>>(
+        &self,
+        game: &str,
+        mod_id: T,
+    ) -> Result<HasEndorsed, get::GameModError> {
+        /// Only the part of [`GameMod`] needed here.
+        #[derive(Deserialize)]
+        struct Endorsement {
+            endorsement: EndorsementInfo,
+        }
+
+        let Endorsement { endorsement } = self.mod_info_as(game, mod_id.into(), false).await?;
+        Ok(endorsement.status())
+    }
+
+    /// Check that `id` names a mod of `game`, turning it into a [`ModId`].
+    ///
+    /// This is the way to trust a number that came from a user.
+    pub async fn verify_mod(&self, game: &str, id: u64) -> Result<ModId, get::GameModError> {
+        self.mod_info(game, ModId::from_u64(id))
+            .await
+            .map(|game_mod| game_mod.id())
+    }
+
+    /// Find the mods and files whose MD5 hash is `md5`, such as to identify a file on disk.
+    ///
+    /// The same file can be uploaded to several mods, so every match is returned. No match is
+    /// an empty list rather than an error.
+    pub async fn md5_search(
+        &self,
+        game: &str,
+        md5: &str,
+    ) -> Result<Vec<Md5Result>, get::GameModError> {
+        let request = self.build(
+            Method::GET,
+            VERSION,
+            &["games", game, "mods", "md5_search", md5],
+            [],
+        )?;
+        let response = self.send(request).await?;
+
         match response.status() {
-            StatusCode::OK => response.json().await.map_err(get::GameModError::Reqwest),
-            StatusCode::NOT_FOUND => Err(response.json::<err::InvalidAPIKeyError>().await?.into()),
-            StatusCode::UNPROCESSABLE_ENTITY => {
-                unimplemented!(
-                    "I have not yet encountered this return code but it is listed as a valid return code"
-                );
+            StatusCode::OK => response.json().await.map_err(get::GameModError::from),
+            StatusCode::NOT_FOUND => Ok(vec![]),
+            _ => get_error(response).await,
+        }
+    }
+
+    /// Find out which mods and files a file on disk came from, by its MD5 hash.
+    ///
+    /// See [`Api::md5_search`].
+    ///
+    /// # Notes
+    /// The file is hashed a chunk at a time, so large archives are never held in memory, but
+    /// reading it blocks the current thread.
+    #[cfg(feature = "checksum")]
+    pub async fn identify_file(
+        &self,
+        game: &str,
+        path: &std::path::Path,
+    ) -> Result<Vec<Md5Result>, get::GameModError> {
+        use std::io::Read;
+
+        use md5::{Digest, Md5};
+
+        let mut file = std::fs::File::open(path)?;
+        let mut hasher = Md5::new();
+        let mut buf = vec![0; 64 * 1024];
+        loop {
+            match file.read(&mut buf)? {
+                0 => break,
+                n => hasher.update(&buf[..n]),
             }
-            _ => unreachable!("The only three documented return codes are 200, 404, and 422"),
         }
+
+        self.md5_search(game, &format!("{:x}", hasher.finalize()))
+            .await
     }
+}
 
-    /// Get specific mod information.
-    pub async fn mod_info<T: Into<ModId>>(
+impl Api {
+    /// Endorse a mod.
+    ///
+    /// Nexus records endorsements per version, so pass the version of the mod the user has if it
+    /// is known.
+    pub async fn endorse<T: Into<ModId>>(
         &self,
         game: &str,
         id: T,
-    ) -> Result<GameMod, get::GameModError> {
-        let id = id.into();
-        let response = self
+        version: Option<&str>,
+    ) -> Result<(), post::EndorseError> {
+        self.endorse_or_abstain(game, id.into(), version, "endorse")
+            .await
+    }
+
+    /// Abstain from endorsing a mod.
+    pub async fn abstain<T: Into<ModId>>(
+        &self,
+        game: &str,
+        id: T,
+        version: Option<&str>,
+    ) -> Result<(), post::EndorseError> {
+        self.endorse_or_abstain(game, id.into(), version, "abstain")
+            .await
+    }
+
+    /// Endorse many mods at once.
+    ///
+    /// At most `concurrency` requests are in flight at once. Each result is tagged with the mod it
+    /// belongs to and results are in the same order as `mods`.
+    pub async fn endorse_all(
+        &self,
+        game: &str,
+        mods: &[(ModId, Option<String>)],
+        concurrency: usize,
+    ) -> Vec<(ModId, Result<(), post::EndorseError>)> {
+        stream::iter(mods)
+            .map(|(id, version)| async move {
+                (*id, self.endorse(game, *id, version.as_deref()).await)
+            })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    async fn endorse_or_abstain(
+        &self,
+        game: &str,
+        id: ModId,
+        version: Option<&str>,
+        action: &str,
+    ) -> Result<(), post::EndorseError> {
+        let request = self
             .build(
-                Method::GET,
+                Method::POST,
                 VERSION,
-                &["games", game, "mods", id.to_string().as_str()],
-                &[],
-            )
-            .send()
-            .await?;
+                &["games", game, "mods", id.to_string().as_str(), action],
+                [],
+            )?
+            .form(
+                &version
+                    .map(|v| ("version", v))
+                    .into_iter()
+                    .collect::<HashMap<_, _>>(),
+            );
+        let response = self.send(request).await?;
 
         match response.status() {
-            StatusCode::OK => response.json().await.map_err(get::GameModError::Reqwest),
-            StatusCode::NOT_FOUND => Err(response.json::<err::InvalidAPIKeyError>().await?.into()),
-            StatusCode::UNPROCESSABLE_ENTITY => {
-                unimplemented!(
-                    "I have not yet encountered this return code but it is listed as a valid return code"
-                );
+            StatusCode::OK => Ok(()),
+            StatusCode::UNAUTHORIZED => {
+                Err(response.json::<err::InvalidAPIKeyError>().await?.into())
             }
-            _ => unreachable!("The only three documented return codes are 200, 404, and 422"),
+            StatusCode::FORBIDDEN => Err(response.json::<err::EndorseRefused>().await?.into()),
+            StatusCode::NOT_FOUND => Err(response.json::<err::ModNotFoundError>().await?.into()),
+            _ => unexpected(response),
         }
     }
 }
@@ -328,40 +1351,89 @@ impl Api {
 /// - [x] `GET` [`v1/games/{game_domain_name}`](`Api::game`)
 impl Api {
     /// Get a list of all games tracked by NexusMods.
+    ///
+    /// Games still awaiting approval are left out; see [`Api::games_filtered`].
     pub async fn games(&self) -> Result<Vec<GameId>, get::GameModError> {
-        let response = self
-            .build(Method::GET, VERSION, &["games"], &[])
-            .send()
-            .await?;
+        self.games_filtered(false).await
+    }
 
-        match response.status() {
-            StatusCode::OK => response.json().await.map_err(get::GameModError::Reqwest),
-            StatusCode::NOT_FOUND => Err(response.json::<err::InvalidAPIKeyError>().await?.into()),
-            StatusCode::UNPROCESSABLE_ENTITY => {
-                unimplemented!(
-                    "I have not yet encountered this return code but it is listed as a valid return code"
-                );
-            }
-            _ => unreachable!("The only three documented return codes are 200, 404, and 422"),
+    /// Like [`Api::games`], but reuse the list from an earlier call if it was fetched less than
+    /// `max_age` ago.
+    ///
+    /// The list is shared across every clone of the [`Api`], and is independent of the `cache`
+    /// feature.
+    pub async fn games_cached(
+        &self,
+        max_age: Duration,
+    ) -> Result<Arc<[GameId]>, get::GameModError> {
+        if let Some((fetched, games)) = &*self.games.lock().unwrap()
+            && fetched.elapsed() < max_age
+        {
+            return Ok(Arc::clone(games));
         }
+
+        let games: Arc<[GameId]> = self.games().await?.into();
+        *self.games.lock().unwrap() = Some((Instant::now(), Arc::clone(&games)));
+        Ok(games)
+    }
+
+    /// Like [`Api::games`], but optionally include games that are still awaiting approval.
+    pub async fn games_filtered(
+        &self,
+        include_unapproved: bool,
+    ) -> Result<Vec<GameId>, get::GameModError> {
+        let request = self.build(
+            Method::GET,
+            VERSION,
+            &["games"],
+            [(
+                "include_unapproved",
+                if include_unapproved { "true" } else { "false" },
+            )],
+        )?;
+        let response = self.send_conditional(request).await?;
+
+        get_json(response).await
+    }
+
+    /// Like [`Api::games`], but yield each game as soon as it has been received instead of
+    /// collecting the whole (large) list in memory first.
+    pub fn games_stream(&self) -> impl Stream<Item = Result<GameId, get::GameModError>> + '_ {
+        stream::once(async move {
+            let request = self.build(Method::GET, VERSION, &["games"], [])?;
+            let response = self.send(request).await?;
+
+            match response.status() {
+                StatusCode::OK => Ok(response),
+                _ => get_error(response).await,
+            }
+        })
+        .flat_map(|response| match response {
+            Ok(response) => json_stream::array_elements(response.bytes_stream()).left_stream(),
+            Err(e) => stream::iter([Err(e)]).right_stream(),
+        })
     }
 
     /// Get information about a single game.
+    ///
+    /// Nexus is asked whether the game changed since the last call with `If-None-Match`, and the
+    /// previous response is reused if it didn't.
     pub async fn game(&self, game: &str) -> Result<GameId, get::GameModError> {
-        let response = self
-            .build(Method::GET, VERSION, &["games", game], &[])
-            .send()
-            .await?;
+        let request = self.build(Method::GET, VERSION, &["games", game], [])?;
+        let response = self.send_conditional(request).await?;
+
+        get_json(response).await
+    }
+
+    /// Does Nexus know about a game with this domain name, such as `skyrimspecialedition`?
+    pub async fn game_exists(&self, game: &str) -> Result<bool, get::GameModError> {
+        let request = self.build(Method::GET, VERSION, &["games", game], [])?;
+        let response = self.send_conditional(request).await?;
 
         match response.status() {
-            StatusCode::OK => response.json().await.map_err(get::GameModError::Reqwest),
-            StatusCode::NOT_FOUND => Err(response.json::<err::InvalidAPIKeyError>().await?.into()),
-            StatusCode::UNPROCESSABLE_ENTITY => {
-                unimplemented!(
-                    "I have not yet encountered this return code but it is listed as a valid return code"
-                );
-            }
-            _ => unreachable!("The only three documented return codes are 200, 404, and 422"),
+            StatusCode::OK => Ok(true),
+            StatusCode::NOT_FOUND => Ok(false),
+            _ => get_error(response).await,
         }
     }
 }
@@ -370,7 +1442,7 @@ impl Api {
 ///
 /// - [x] `GET` [`v1/games/{game_domain_name}/mods/{mod_id}/files`](`Api::mod_files`)
 /// - [x] `GET` [`v1/games/{game_domain_name}/mods/{mod_id}/files/{file_id}`](`Api::mod_file`)
-/// - [ ] `GET` `v1/games/{game_domain_name}/mods/{mod_id}/files/{id}/download_link`
+/// - [x] `GET` [`v1/games/{game_domain_name}/mods/{mod_id}/files/{id}/download_link`](`Api::download_link`)
 impl Api {
     /// Based on a game and a [`ModId`], get data about the download files the mod provides.
     pub async fn mod_files<S: Into<ModId>>(
@@ -380,29 +1452,106 @@ impl Api {
         category: Option<CategoryName>,
     ) -> Result<ModFiles, get::GameModError> {
         let mod_id = mod_id.into();
+        let request = self.build(
+            Method::GET,
+            VERSION,
+            &["games", game, "mods", mod_id.to_string().as_str(), "files"],
+            category.iter().map(|c| ("category", c.to_header_str())),
+        )?;
+        let response = self.send(request).await?;
+
+        get_json::<ModFiles>(response)
+            .await
+            .map(|files| files.with_context(game, mod_id))
+    }
+
+    /// Check whether an installed file is still the newest version, following the mod's update
+    /// chain if it isn't.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cyclone_mod::{Api, err::get::GameModError, request::FileStatus};
+    /// # async fn run(api: Api) -> Result<(), GameModError> {
+    /// let mod_id = api.verify_mod("skyrim", 3863).await?;
+    /// match api.file_update_status("skyrim", mod_id, 1000172397).await? {
+    ///     FileStatus::Current => println!("up to date"),
+    ///     FileStatus::Superseded { latest } => println!("update available: {latest}"),
+    ///     FileStatus::Missing => println!("file no longer exists"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn file_update_status<S: Into<ModId>>(
+        &self,
+        game: &str,
+        mod_id: S,
+        file_id: u64,
+    ) -> Result<FileStatus, get::GameModError> {
+        Ok(self
+            .mod_files(game, mod_id, None)
+            .await?
+            .file_status(file_id))
+    }
+
+    /// Fetch the list of files inside a file's archive, from [`ModFile::content_preview`].
+    ///
+    /// The preview is served from a different host than the API, so the API key is never sent along.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cyclone_mod::{Api, err::get::GameModError, request::ModId};
+    /// # async fn run(api: Api, id: ModId) -> Result<(), GameModError> {
+    /// for file in api.mod_files("skyrim", id, None).await?.iter_files() {
+    ///     let preview = api.preview_of(file).await?;
+    ///     println!("{}: {} entries", file.file_name(), preview.files().len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn preview_of(&self, file: &ModFile) -> Result<PreviewFileRoot, get::GameModError> {
         let response = self
-            .build(
-                Method::GET,
-                VERSION,
-                &["games", game, "mods", mod_id.to_string().as_str(), "files"],
-                &category
-                    .iter()
-                    .map(|c| ("category", c.to_header_str()))
-                    .collect::<Vec<_>>(),
-            )
-            .send()
+            .get_external(file.content_preview().clone())
+            .await?
+            .error_for_status()?;
+
+        response.json().await.map_err(get::GameModError::from)
+    }
+
+    /// Fetch a file's virus scan report and read the verdict out of it.
+    ///
+    /// Files without a report are [`ScanVerdict::Unknown`] without making a request. See
+    /// [`ScanVerdict::from_report`] for how brittle reading the report is.
+    pub async fn scan_verdict(&self, file: &ModFile) -> Result<ScanVerdict, get::GameModError> {
+        let VirusScan::Scanned { url } = file.virus_scan() else {
+            return Ok(ScanVerdict::Unknown);
+        };
+        let report = self
+            .get_external(url.clone())
+            .await?
+            .error_for_status()?
+            .text()
             .await?;
 
-        match response.status() {
-            StatusCode::OK => response.json().await.map_err(get::GameModError::Reqwest),
-            StatusCode::NOT_FOUND => Err(response.json::<err::InvalidAPIKeyError>().await?.into()),
-            StatusCode::UNPROCESSABLE_ENTITY => {
-                unimplemented!(
-                    "I have not yet encountered this return code but it is listed as a valid return code"
-                );
-            }
-            _ => unreachable!("The only three documented return codes are 200, 404, and 422"),
-        }
+        Ok(ScanVerdict::from_report(&report))
+    }
+
+    /// Fetch the files of many mods at once.
+    ///
+    /// At most `concurrency` requests are in flight at once. Each result is tagged with the mod ID
+    /// it belongs to and results are in the same order as `ids`.
+    pub async fn all_mod_files(
+        &self,
+        game: &str,
+        ids: &[u64],
+        concurrency: usize,
+    ) -> Vec<(u64, Result<ModFiles, get::GameModError>)> {
+        stream::iter(ids.iter().copied())
+            .map(|id| async move { (id, self.mod_files(game, ModId::from_u64(id), None).await) })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
     }
 
     pub async fn mod_file<S: Into<ModId>>(
@@ -412,32 +1561,100 @@ impl Api {
         file_id: u64,
     ) -> Result<ModFile, get::GameModError> {
         let mod_id = mod_id.into();
-        let response = self
-            .build(
-                Method::GET,
-                VERSION,
-                &[
-                    "games",
-                    game,
-                    "mods",
-                    mod_id.to_string().as_str(),
-                    "files",
-                    file_id.to_string().as_str(),
-                ],
-                &[],
-            )
-            .send()
-            .await?;
+        let request = self.build(
+            Method::GET,
+            VERSION,
+            &[
+                "games",
+                game,
+                "mods",
+                mod_id.to_string().as_str(),
+                "files",
+                file_id.to_string().as_str(),
+            ],
+            [],
+        )?;
+        let response = self.send(request).await?;
 
-        match response.status() {
-            StatusCode::OK => response.json().await.map_err(get::GameModError::Reqwest),
-            StatusCode::NOT_FOUND => Err(response.json::<err::InvalidAPIKeyError>().await?.into()),
-            StatusCode::UNPROCESSABLE_ENTITY => {
-                unimplemented!(
-                    "I have not yet encountered this return code but it is listed as a valid return code"
-                );
-            }
-            _ => unreachable!("The only three documented return codes are 200, 404, and 422"),
-        }
+        get_json::<ModFile>(response)
+            .await
+            .map(|file| file.with_context(game, mod_id))
+    }
+
+    /// Generate download links for a file.
+    ///
+    /// Premium users may pass [`None`] for `nxm`. Everyone else needs the [`NxmParams`] from an
+    /// `nxm://` link the website generated for them, and get [`get::GameModError::Forbidden`]
+    /// without one.
+    ///
+    /// The links expire, so they are never cached.
+    pub async fn download_link<S: Into<ModId>>(
+        &self,
+        game: &str,
+        mod_id: S,
+        file_id: u64,
+        nxm: Option<NxmParams>,
+    ) -> Result<Vec<DownloadLink>, get::GameModError> {
+        let mod_id = mod_id.into();
+        let expires = nxm.as_ref().map(|nxm| nxm.expires().to_string());
+        let params = match (&nxm, &expires) {
+            (Some(nxm), Some(expires)) => vec![("key", nxm.key()), ("expires", expires.as_str())],
+            _ => vec![],
+        };
+        let request = self.build(
+            Method::GET,
+            VERSION,
+            &[
+                "games",
+                game,
+                "mods",
+                mod_id.to_string().as_str(),
+                "files",
+                file_id.to_string().as_str(),
+                "download_link",
+            ],
+            params,
+        )?;
+        let response = self.send_with(request, false).await?;
+
+        get_json(response).await
+    }
+
+    /// Generate download links for a [`ModFile`] obtained from [`Api::mod_files`] or
+    /// [`Api::mod_file`].
+    ///
+    /// # Errors
+    /// [`get::GameModError::MissingFileContext`] if `file` did not come from either of those.
+    pub async fn download_file(
+        &self,
+        file: &ModFile,
+        nxm: Option<NxmParams>,
+    ) -> Result<Vec<DownloadLink>, get::GameModError> {
+        let (Some(game), Some(mod_id)) = (file.game(), file.mod_id()) else {
+            return Err(get::GameModError::MissingFileContext);
+        };
+
+        self.download_link(game, mod_id, file.file_id(), nxm).await
+    }
+
+    /// Generate download links for the primary file of a mod.
+    ///
+    /// # Errors
+    /// [`get::GameModError::NoPrimaryFile`] if the mod doesn't mark any file as primary.
+    pub async fn primary_download_link<S: Into<ModId>>(
+        &self,
+        game: &str,
+        mod_id: S,
+        nxm: Option<NxmParams>,
+    ) -> Result<Vec<DownloadLink>, get::GameModError> {
+        let mod_id = mod_id.into();
+        let files = self.mod_files(game, mod_id, None).await?;
+        let primary = files
+            .iter_files()
+            .find(|file| file.is_primary())
+            .ok_or(get::GameModError::NoPrimaryFile)?;
+
+        self.download_link(game, mod_id, primary.file_id(), nxm)
+            .await
     }
 }
@@ -0,0 +1,165 @@
+//! In-memory response cache used by [`Api`](`crate::Api`).
+//!
+//! `reqwest` can't build a [`Response`] by hand on WASM, so there the cache never answers and
+//! every request goes to the network.
+#![cfg_attr(target_arch = "wasm32", allow(dead_code, unused_imports))]
+
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use bytes::Bytes;
+use reqwest::{
+    Method, Response, StatusCode, Url,
+    header::{CACHE_CONTROL, ETAG, HeaderMap, HeaderValue},
+};
+
+type Key = (Method, Url);
+
+/// What the cache knows about a request that is about to be sent.
+pub(crate) enum Lookup {
+    /// Still fresh, no need to hit the network.
+    Fresh(Response),
+    /// Expired, but can be revalidated with `If-None-Match`.
+    Stale(HeaderValue),
+    Miss,
+}
+
+struct Entry {
+    headers: HeaderMap,
+    body: Bytes,
+    etag: Option<HeaderValue>,
+    fetched: Instant,
+    ttl: Duration,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Entry {
+    fn response(&self) -> Response {
+        let mut response = http::Response::new(self.body.clone());
+        *response.headers_mut() = self.headers.clone();
+        response.into()
+    }
+}
+
+pub(crate) struct ResponseCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<Key, Entry>>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl ResponseCache {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::default(),
+        }
+    }
+
+    pub(crate) fn lookup(&self, method: &Method, url: &Url) -> Lookup {
+        let entries = self.entries.lock().unwrap();
+        match entries.get(&(method.clone(), url.clone())) {
+            Some(entry) if entry.fetched.elapsed() < entry.ttl => Lookup::Fresh(entry.response()),
+            Some(Entry {
+                etag: Some(etag), ..
+            }) => Lookup::Stale(etag.clone()),
+            _ => Lookup::Miss,
+        }
+    }
+
+    /// Cache a successful response, or answer a `304 Not Modified` from the cache.
+    ///
+    /// Anything else is passed through untouched, including a `304` for an entry that has been
+    /// cleared since the request was sent.
+    pub(crate) async fn store(
+        &self,
+        method: Method,
+        url: Url,
+        response: Response,
+    ) -> Result<Response, reqwest::Error> {
+        let key = (method, url);
+
+        match response.status() {
+            StatusCode::NOT_MODIFIED => {
+                let mut entries = self.entries.lock().unwrap();
+                match entries.get_mut(&key) {
+                    Some(entry) => {
+                        entry.fetched = Instant::now();
+                        Ok(entry.response())
+                    }
+                    None => Ok(response),
+                }
+            }
+            StatusCode::OK => {
+                let headers = response.headers().clone();
+                let body = response.bytes().await?;
+
+                let directives = headers
+                    .get_all(CACHE_CONTROL)
+                    .iter()
+                    .filter_map(|v| v.to_str().ok())
+                    .flat_map(|v| v.split(','))
+                    .map(str::trim)
+                    .collect::<Vec<_>>();
+                let no_store = directives.contains(&"no-store");
+
+                let entry = Entry {
+                    etag: headers.get(ETAG).cloned(),
+                    ttl: if directives.contains(&"no-cache") {
+                        Duration::ZERO
+                    } else {
+                        directives
+                            .iter()
+                            .find_map(|d| d.strip_prefix("max-age="))
+                            .and_then(|secs| secs.parse().ok())
+                            .map_or(self.ttl, |secs| self.ttl.min(Duration::from_secs(secs)))
+                    },
+                    fetched: Instant::now(),
+                    headers,
+                    body,
+                };
+                let response = entry.response();
+
+                // Nothing to gain from keeping a response that is immediately stale and can't be
+                // revalidated.
+                if !no_store && (entry.etag.is_some() || !entry.ttl.is_zero()) {
+                    self.entries.lock().unwrap().insert(key, entry);
+                }
+
+                Ok(response)
+            }
+            _ => Ok(response),
+        }
+    }
+
+    pub(crate) fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl ResponseCache {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::default(),
+        }
+    }
+
+    pub(crate) fn lookup(&self, _method: &Method, _url: &Url) -> Lookup {
+        Lookup::Miss
+    }
+
+    pub(crate) async fn store(
+        &self,
+        _method: Method,
+        _url: Url,
+        response: Response,
+    ) -> Result<Response, reqwest::Error> {
+        Ok(response)
+    }
+
+    pub(crate) fn clear(&self) {}
+}
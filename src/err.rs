@@ -1,9 +1,11 @@
 use std::fmt::Display;
 
-use serde::{Deserialize, Serialize};
+use reqwest::StatusCode;
+use serde::Deserialize;
 use thiserror::Error;
 
-#[derive(Debug, Error, Serialize, Deserialize)]
+#[derive(Debug, Error, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct InvalidAPIKeyError {
     pub message: String,
 }
@@ -14,7 +16,8 @@ impl Display for InvalidAPIKeyError {
     }
 }
 
-#[derive(Debug, Error, Serialize, Deserialize)]
+#[derive(Debug, Error, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct ModNotFoundError {
     pub message: String,
 }
@@ -25,50 +28,298 @@ impl Display for ModNotFoundError {
     }
 }
 
-#[derive(Debug, Error, Serialize, Deserialize)]
-pub struct UntrackedOrInvalidMod {
+#[derive(Debug, Error, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct InvalidGame {
+    pub code: u64,
     pub message: String,
 }
 
-impl Display for UntrackedOrInvalidMod {
+impl Display for InvalidGame {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.message)
     }
 }
 
-#[derive(Debug, Error, Serialize, Deserialize)]
-pub struct InvalidGame {
-    pub code: u64,
+/// Nexus refused an endorsement, for example because the user hasn't downloaded the mod or
+/// is its author.
+#[derive(Debug, Error, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct EndorseRefused {
     pub message: String,
 }
 
-impl Display for InvalidGame {
+impl Display for EndorseRefused {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.message)
     }
 }
 
+/// The mod contains adult content and the user has not enabled adult content in their Nexus
+/// settings.
+///
+/// Nexus answers these with the same statuses as a missing or forbidden mod, so they are told
+/// apart by looking for "adult" in the message it sends. If Nexus rewords that message, these
+/// show up as the plain not found or forbidden errors again.
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct AdultContentBlocked {
+    pub message: String,
+    /// Which of `403` or `404` the endpoint answered with, which decides the error's
+    /// [`StatusKind`].
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub status: StatusCode,
+}
+
+impl AdultContentBlocked {
+    /// Does an error message from Nexus refer to the adult content filter?
+    ///
+    /// A plain substring check, see the type's documentation.
+    pub(crate) fn matches(message: &str) -> bool {
+        message.to_ascii_lowercase().contains("adult")
+    }
+}
+
+impl Display for AdultContentBlocked {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// See [`GameDomain::new`](`crate::request::GameDomain::new`).
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("`{0}` is not a valid game domain name")]
+pub struct InvalidGameDomain(pub String);
+
+/// Reasons an [`Api`](`crate::Api`) could not be built.
+#[derive(Debug, Error)]
+pub enum ApiBuildError {
+    #[error("API key is not a valid header value")]
+    InvalidKey(#[from] reqwest::header::InvalidHeaderValue),
+    #[error("application name or version is not a valid header value")]
+    InvalidAppInfo(#[source] reqwest::header::InvalidHeaderValue),
+    #[error("language is not a valid header value")]
+    InvalidLanguage(#[source] reqwest::header::InvalidHeaderValue),
+    #[error(transparent)]
+    Client(#[from] reqwest::Error),
+    #[error("requests per second must be positive and finite, found {0}")]
+    InvalidRate(f64),
+    #[error("`{0}` is not an absolute URL with a host")]
+    InvalidBaseUrl(String),
+    #[error("default game is not valid")]
+    InvalidDefaultGame(#[from] InvalidGameDomain),
+}
+
+/// Reasons a hex colour could not be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum ColourError {
+    #[error("expected 3 or 6 hex digits, found {0}")]
+    InvalidLength(usize),
+    #[error("`{0}` is not a hex digit")]
+    InvalidDigit(char),
+}
+
+/// Reasons an `nxm://` link could not be parsed.
+#[derive(Debug, Error)]
+pub enum NxmParseError {
+    #[error(transparent)]
+    InvalidUrl(#[from] url::ParseError),
+    #[error("expected an `nxm` link, found `{0}`")]
+    WrongScheme(String),
+    #[error("link does not name a game")]
+    MissingGame,
+    #[error("expected `/mods/{{mod_id}}/files/{{file_id}}`, found `{0}`")]
+    MalformedPath(String),
+    #[error("link is missing the `{0}` query parameter")]
+    MissingQuery(&'static str),
+    #[error("`{field}` is not a number: `{value}`")]
+    InvalidNumber { field: &'static str, value: String },
+}
+
+/// Any error from a request made through [`Api`](`crate::Api`).
+///
+/// Every per-request error converts into this, so a function calling several endpoints can use
+/// `?` on all of them. Match on the inner error for the specifics.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Validate(#[from] validate::ValidateError),
+    #[error(transparent)]
+    TrackMod(#[from] post::TrackModError),
+    #[error(transparent)]
+    Endorse(#[from] post::EndorseError),
+    #[error(transparent)]
+    GameMod(#[from] get::GameModError),
+    #[error(transparent)]
+    DeleteMod(#[from] delete::DeleteModError),
+}
+
+impl Error {
+    /// What kind of status the request failed with.
+    pub fn kind(&self) -> StatusKind {
+        match self {
+            Self::Validate(e) => e.kind(),
+            Self::TrackMod(e) => e.kind(),
+            Self::Endorse(e) => e.kind(),
+            Self::GameMod(e) => e.kind(),
+            Self::DeleteMod(e) => e.kind(),
+        }
+    }
+
+    /// Is this error likely to go away if the request is retried?
+    ///
+    /// True for rate limiting, server errors, timeouts, and connection failures. [`Api`] never
+    /// retries a request by itself, this is for deciding whether your own retry loop should.
+    ///
+    /// [`Api`]: crate::Api
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Validate(e) => e.is_transient(),
+            Self::TrackMod(e) => e.is_transient(),
+            Self::Endorse(e) => e.is_transient(),
+            Self::GameMod(e) => e.is_transient(),
+            Self::DeleteMod(e) => e.is_transient(),
+        }
+    }
+}
+
+/// Broad category of an HTTP status, for deciding what to tell a user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusKind {
+    /// Any `2xx`.
+    Ok,
+    /// `401` or `403`, such as a bad API key or content the user may not see.
+    Auth,
+    /// `404`.
+    NotFound,
+    /// `422`.
+    Unprocessable,
+    /// `429`, see [`Api::rate_limits`](`crate::Api::rate_limits`).
+    RateLimited,
+    /// Any `5xx`.
+    Server,
+    /// Anything else, or no status at all such as for a timeout.
+    Unknown,
+}
+
+impl From<StatusCode> for StatusKind {
+    fn from(status: StatusCode) -> Self {
+        match status {
+            s if s.is_success() => Self::Ok,
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Self::Auth,
+            StatusCode::NOT_FOUND => Self::NotFound,
+            StatusCode::UNPROCESSABLE_ENTITY => Self::Unprocessable,
+            StatusCode::TOO_MANY_REQUESTS => Self::RateLimited,
+            s if s.is_server_error() => Self::Server,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// [`StatusKind`] of a failed request.
+fn status_kind(e: &reqwest::Error) -> StatusKind {
+    e.status().map_or(StatusKind::Unknown, StatusKind::from)
+}
+
+/// Would retrying a request that failed with `e` plausibly succeed?
+fn is_transient(e: &reqwest::Error) -> bool {
+    #[cfg(not(target_arch = "wasm32"))]
+    if e.is_connect() {
+        return true;
+    }
+
+    e.is_timeout()
+        || e.status().is_some_and(|status| {
+            matches!(
+                status,
+                StatusCode::TOO_MANY_REQUESTS
+                    | StatusCode::INTERNAL_SERVER_ERROR
+                    | StatusCode::BAD_GATEWAY
+                    | StatusCode::SERVICE_UNAVAILABLE
+                    | StatusCode::GATEWAY_TIMEOUT
+            )
+        })
+}
+
+/// Nexus answered with a status the endpoint doesn't document, and that isn't an error status
+/// either. Error statuses are reported as a `Reqwest` error instead.
+#[derive(Debug, Error)]
+#[error("unexpected status {0}")]
+pub struct UnexpectedStatus(pub StatusCode);
+
+/// Implement `kind`, `is_transient`, and `From<reqwest::Error>` for a per-request error.
+///
+/// The arms map the variants only that error has to their [`StatusKind`].
+macro_rules! request_error {
+    ($error:ident { $($variant:pat => $kind:expr,)* }) => {
+        impl $error {
+            /// What kind of status the request failed with.
+            pub fn kind(&self) -> StatusKind {
+                match self {
+                    Self::Reqwest(e) => $crate::err::status_kind(e),
+                    Self::UnexpectedStatus(e) => e.0.into(),
+                    $($variant => $kind,)*
+                    _ => StatusKind::Unknown,
+                }
+            }
+
+            /// Is this error likely to go away if the request is retried?
+            ///
+            /// True for rate limiting, server errors, timeouts, and connection failures.
+            /// [`Api`](crate::Api) never retries a request by itself, this is for deciding
+            /// whether your own retry loop should.
+            pub fn is_transient(&self) -> bool {
+                match self {
+                    Self::Reqwest(e) => $crate::err::is_transient(e),
+                    Self::Timeout(_) => true,
+                    _ => false,
+                }
+            }
+        }
+
+        impl From<reqwest::Error> for $error {
+            fn from(e: reqwest::Error) -> Self {
+                if e.is_timeout() {
+                    Self::Timeout(e)
+                } else {
+                    Self::Reqwest(e)
+                }
+            }
+        }
+    };
+}
+
 pub mod validate {
     use thiserror::Error;
 
-    use crate::err::InvalidAPIKeyError;
+    use crate::err::{InvalidAPIKeyError, StatusKind, UnexpectedStatus};
 
     #[derive(Debug, Error)]
     pub enum ValidateError {
         #[error(transparent)]
-        Reqwest(#[from] reqwest::Error),
+        Reqwest(reqwest::Error),
+        #[error("request timed out")]
+        Timeout(#[source] reqwest::Error),
         #[error(transparent)]
         SerdeJson(#[from] serde_json::Error),
+        #[error("could not build request URL")]
+        UrlBuild(#[from] url::ParseError),
+        #[error(transparent)]
+        UnexpectedStatus(#[from] UnexpectedStatus),
         #[error(transparent)]
         InvalidAPIKey(#[from] InvalidAPIKeyError),
     }
+
+    request_error!(ValidateError {
+        Self::InvalidAPIKey(_) => StatusKind::Auth,
+    });
 }
 
 pub mod post {
     use thiserror::Error;
 
     use crate::{
-        err::{InvalidAPIKeyError, ModNotFoundError},
+        err::{EndorseRefused, InvalidAPIKeyError, ModNotFoundError, StatusKind, UnexpectedStatus},
         request::ModId,
     };
 
@@ -83,48 +334,132 @@ pub mod post {
     #[derive(Debug, Error)]
     pub enum TrackModError {
         #[error(transparent)]
-        Reqwest(#[from] reqwest::Error),
+        Reqwest(reqwest::Error),
+        #[error("request timed out")]
+        Timeout(#[source] reqwest::Error),
         #[error(transparent)]
         SerdeJson(#[from] serde_json::Error),
+        #[error("could not build request URL")]
+        UrlBuild(#[from] url::ParseError),
+        #[error(transparent)]
+        UnexpectedStatus(#[from] UnexpectedStatus),
         #[error(transparent)]
         InvalidAPIKey(#[from] InvalidAPIKeyError),
         #[error(transparent)]
         ModNotFound(#[from] ModNotFoundError),
     }
+
+    request_error!(TrackModError {
+        Self::InvalidAPIKey(_) => StatusKind::Auth,
+        Self::ModNotFound(_) => StatusKind::NotFound,
+    });
+
+    #[derive(Debug, Error)]
+    pub enum EndorseError {
+        #[error(transparent)]
+        Reqwest(reqwest::Error),
+        #[error("request timed out")]
+        Timeout(#[source] reqwest::Error),
+        #[error(transparent)]
+        SerdeJson(#[from] serde_json::Error),
+        #[error("could not build request URL")]
+        UrlBuild(#[from] url::ParseError),
+        #[error(transparent)]
+        UnexpectedStatus(#[from] UnexpectedStatus),
+        #[error(transparent)]
+        InvalidAPIKey(#[from] InvalidAPIKeyError),
+        #[error(transparent)]
+        ModNotFound(#[from] ModNotFoundError),
+        #[error(transparent)]
+        Refused(#[from] EndorseRefused),
+    }
+
+    request_error!(EndorseError {
+        Self::InvalidAPIKey(_) | Self::Refused(_) => StatusKind::Auth,
+        Self::ModNotFound(_) => StatusKind::NotFound,
+    });
 }
 
 pub mod get {
     use thiserror::Error;
 
-    use crate::err::{InvalidAPIKeyError, InvalidGame};
+    use crate::err::{
+        AdultContentBlocked, InvalidAPIKeyError, InvalidGame, StatusKind, UnexpectedStatus,
+    };
 
     #[derive(Debug, Error)]
     pub enum GameModError {
         #[error(transparent)]
-        Reqwest(#[from] reqwest::Error),
+        Reqwest(reqwest::Error),
+        #[error("request timed out")]
+        Timeout(#[source] reqwest::Error),
         #[error(transparent)]
         SerdeJson(#[from] serde_json::Error),
+        #[error("could not build request URL")]
+        UrlBuild(#[from] url::ParseError),
+        #[error(transparent)]
+        UnexpectedStatus(#[from] UnexpectedStatus),
         #[error(transparent)]
         InvalidAPIKey(#[from] InvalidAPIKeyError),
         #[error(transparent)]
         InvalidGameID(#[from] InvalidGame),
+        #[error("mod file does not know which game or mod it belongs to")]
+        MissingFileContext,
+        #[error("mod has no primary file")]
+        NoPrimaryFile,
+        #[error("could not read local file")]
+        Io(#[from] std::io::Error),
+        #[error(transparent)]
+        AdultContentBlocked(#[from] AdultContentBlocked),
+        /// Nexus refused the request, usually because it needs a premium membership. `message`
+        /// is Nexus' explanation and is fine to show to the user.
+        #[error("{message}")]
+        Forbidden { message: String },
+        /// Nexus doesn't know the game, mod or file asked for. `message` is Nexus' explanation.
+        #[error("{message}")]
+        NotFound { message: String },
+        #[error("Nexus only reports updates from the last month, not since {since}")]
+        HistoryUnavailable { since: time::UtcDateTime },
     }
+
+    request_error!(GameModError {
+        Self::InvalidAPIKey(_) | Self::Forbidden { .. } => StatusKind::Auth,
+        Self::AdultContentBlocked(e) => e.status.into(),
+        Self::InvalidGameID(_) | Self::NotFound { .. } => StatusKind::NotFound,
+    });
 }
 
 pub mod delete {
     use thiserror::Error;
 
-    use crate::err::{InvalidAPIKeyError, UntrackedOrInvalidMod};
+    use crate::err::{InvalidAPIKeyError, StatusKind, UnexpectedStatus};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum UntrackStatus {
+        /// The mod was tracked and no longer is.
+        Removed,
+        /// The mod wasn't tracked to begin with, for example because an earlier attempt already
+        /// went through.
+        WasNotTracked,
+    }
 
     #[derive(Debug, Error)]
     pub enum DeleteModError {
         #[error(transparent)]
-        Reqwest(#[from] reqwest::Error),
+        Reqwest(reqwest::Error),
+        #[error("request timed out")]
+        Timeout(#[source] reqwest::Error),
         #[error(transparent)]
         SerdeJson(#[from] serde_json::Error),
+        #[error("could not build request URL")]
+        UrlBuild(#[from] url::ParseError),
         #[error(transparent)]
-        InvalidAPIKey(#[from] InvalidAPIKeyError),
+        UnexpectedStatus(#[from] UnexpectedStatus),
         #[error(transparent)]
-        UntrackedOrInvalid(#[from] UntrackedOrInvalidMod),
+        InvalidAPIKey(#[from] InvalidAPIKeyError),
     }
+
+    request_error!(DeleteModError {
+        Self::InvalidAPIKey(_) => StatusKind::Auth,
+    });
 }
@@ -0,0 +1,201 @@
+//! Incremental parsing of top-level JSON arrays, used by
+//! [`Api::games_stream`](`crate::Api::games_stream`).
+
+use bytes::Bytes;
+use futures::{Stream, StreamExt, stream};
+use serde::de::DeserializeOwned;
+
+/// Splits the bytes of a JSON array into the raw bytes of each of its elements, without ever
+/// holding more than one element in memory.
+#[derive(Default)]
+struct ArraySplitter {
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    current: Vec<u8>,
+}
+
+impl ArraySplitter {
+    /// Feed the next chunk of the array, returning every element completed by it.
+    fn feed(&mut self, chunk: &[u8]) -> Vec<Vec<u8>> {
+        let mut done = Vec::new();
+
+        for &b in chunk {
+            if self.in_string {
+                self.current.push(b);
+                if self.escaped {
+                    self.escaped = false;
+                } else if b == b'\\' {
+                    self.escaped = true;
+                } else if b == b'"' {
+                    self.in_string = false;
+                }
+                continue;
+            }
+
+            match b {
+                b'[' | b'{' => {
+                    if self.depth > 0 {
+                        self.current.push(b);
+                    }
+                    self.depth += 1;
+                }
+                b']' | b'}' => {
+                    self.depth = self.depth.saturating_sub(1);
+                    if self.depth > 0 {
+                        self.current.push(b);
+                    }
+                    if self.depth <= 1 {
+                        done.extend(self.take());
+                    }
+                }
+                b',' if self.depth == 1 => done.extend(self.take()),
+                _ if b.is_ascii_whitespace() && self.depth <= 1 => {}
+                _ => {
+                    if b == b'"' {
+                        self.in_string = true;
+                    }
+                    self.current.push(b);
+                }
+            }
+        }
+
+        done
+    }
+
+    fn take(&mut self) -> Option<Vec<u8>> {
+        (!self.current.is_empty()).then(|| std::mem::take(&mut self.current))
+    }
+
+    /// Whatever is left once the input is exhausted, which is only ever an unfinished element.
+    fn finish(mut self) -> Option<Vec<u8>> {
+        if self.depth > 0 || !self.current.is_empty() {
+            Some(self.take().unwrap_or_default())
+        } else {
+            None
+        }
+    }
+}
+
+/// Deserialize each element of the JSON array in `bytes` as soon as it has fully arrived.
+///
+/// A truncated array ends the stream with a [`serde_json::Error`].
+pub(crate) fn array_elements<T, E>(
+    bytes: impl Stream<Item = Result<Bytes, reqwest::Error>> + Unpin,
+) -> impl Stream<Item = Result<T, E>>
+where
+    T: DeserializeOwned,
+    E: From<reqwest::Error> + From<serde_json::Error>,
+{
+    stream::unfold(
+        Some((bytes, ArraySplitter::default())),
+        |state| async move {
+            let (mut bytes, mut splitter) = state?;
+            let items: Vec<Result<T, E>> = match bytes.next().await {
+                Some(Ok(chunk)) => splitter
+                    .feed(&chunk)
+                    .iter()
+                    .map(|raw| serde_json::from_slice(raw).map_err(E::from))
+                    .collect(),
+                Some(Err(e)) => return Some((vec![Err(e.into())], None)),
+                None => {
+                    let rest = splitter.finish()?;
+                    let err = serde_json::from_slice::<T>(&rest).err()?;
+                    return Some((vec![Err(err.into())], None));
+                }
+            };
+            Some((items, Some((bytes, splitter))))
+        },
+    )
+    .flat_map(stream::iter)
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use futures::{StreamExt, executor::block_on, stream};
+    use serde_json::{Value, json};
+
+    use super::{ArraySplitter, array_elements};
+    use crate::err::get::GameModError;
+
+    /// Feed `chunks` one after the other, parsing every element that comes out.
+    fn split(chunks: &[&str]) -> Vec<Value> {
+        let mut splitter = ArraySplitter::default();
+        let elements = chunks
+            .iter()
+            .flat_map(|chunk| splitter.feed(chunk.as_bytes()))
+            .map(|raw| serde_json::from_slice(&raw).unwrap())
+            .collect();
+        assert!(splitter.finish().is_none());
+        elements
+    }
+
+    #[test]
+    fn elements_split_across_chunks() {
+        assert_eq!(
+            split(&["[{\"a\": 1}, {\"b\"", ": 2}, 3", "4, \"x", "y\"]"]),
+            [json!({"a": 1}), json!({"b": 2}), json!(34), json!("xy")],
+        );
+    }
+
+    #[test]
+    fn every_byte_its_own_chunk() {
+        let body = r#"[{"a": [1, 2]}, "b", 3]"#;
+        let chunks = body
+            .char_indices()
+            .map(|(i, c)| &body[i..i + c.len_utf8()])
+            .collect::<Vec<_>>();
+        assert_eq!(split(&chunks), [json!({"a": [1, 2]}), json!("b"), json!(3)]);
+    }
+
+    #[test]
+    fn strings_with_structural_characters() {
+        assert_eq!(
+            split(&[r#"["a]b", "c}d", "e,f", "g\"h", {"i": "]},\""}]"#]),
+            [
+                json!("a]b"),
+                json!("c}d"),
+                json!("e,f"),
+                json!("g\"h"),
+                json!({"i": "]},\""}),
+            ],
+        );
+    }
+
+    #[test]
+    fn escape_split_across_chunks() {
+        assert_eq!(
+            split(&[r#"["a\"#, r#""", "b"]"#]),
+            [json!("a\""), json!("b")]
+        );
+    }
+
+    #[test]
+    fn nested_arrays_and_objects() {
+        assert_eq!(
+            split(&[r#"[[1, [2, 3]], {"a": {"b": [4, {"c": 5}]}}, []]"#]),
+            [
+                json!([1, [2, 3]]),
+                json!({"a": {"b": [4, {"c": 5}]}}),
+                json!([]),
+            ],
+        );
+    }
+
+    #[test]
+    fn empty_array() {
+        assert!(split(&["[", " ", "]"]).is_empty());
+    }
+
+    #[test]
+    fn truncated_array_ends_in_an_error() {
+        let chunks = ["[{\"a\": 1}, {\"b\"", ": 2"].map(|chunk| Ok(Bytes::from(chunk)));
+        let results: Vec<Result<Value, GameModError>> =
+            block_on(array_elements(stream::iter(chunks)).collect());
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap(), &json!({"a": 1}));
+        assert!(matches!(results[1], Err(GameModError::SerdeJson(_))));
+    }
+}
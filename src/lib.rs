@@ -4,10 +4,26 @@
 )]
 //! A rust wrapper for the Nexus Mods API.
 
+// Also builds for the browser. To check, run:
+// `cargo check --target wasm32-unknown-unknown --all-features`
+
 pub(crate) static VERSION: &str = "v1";
 
 mod api;
+mod cache;
 pub mod err;
+mod json_stream;
+#[cfg(feature = "api-trait")]
+mod nexus_api;
+pub mod nxm;
 pub mod request;
+mod scope;
+#[cfg(test)]
+mod tests;
+mod throttle;
 
-pub use api::Api;
+pub use api::{Api, ApiBuilder};
+pub use err::Error;
+#[cfg(feature = "api-trait")]
+pub use nexus_api::NexusApi;
+pub use scope::GameScope;
@@ -0,0 +1,173 @@
+use async_trait::async_trait;
+
+use crate::{
+    Api,
+    err::{delete, get, post, validate},
+    nxm::NxmParams,
+    request::{
+        CategoryName, Changelog, DownloadLink, Endorsements, GameId, GameMod, ModFile, ModFiles,
+        ModId, ModUpdated, TimePeriod, TrackedModsRaw, Validate,
+    },
+};
+
+/// The network facing surface of [`Api`], as a trait.
+///
+/// Code that only depends on this trait can be tested against a fake implementation instead of
+/// the real Nexus API. Every method forwards to the [`Api`] method of the same name.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use cyclone_mod::{NexusApi, err::get::GameModError};
+/// async fn game_names(api: &impl NexusApi) -> Result<Vec<String>, GameModError> {
+///     Ok(api
+///         .games()
+///         .await?
+///         .iter()
+///         .map(|game| game.pretty_name().to_string())
+///         .collect())
+/// }
+/// ```
+///
+/// On WASM the returned futures are not `Send`, since nothing in the browser is.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait NexusApi: Send + Sync {
+    async fn validate(&self) -> Result<Validate, validate::ValidateError>;
+
+    async fn tracked_mods(&self) -> Result<TrackedModsRaw, validate::ValidateError>;
+
+    async fn track_mod(
+        &self,
+        game: &str,
+        id: u64,
+    ) -> Result<post::PostModStatus, post::TrackModError>;
+
+    async fn untrack_mod(
+        &self,
+        game: &str,
+        id: ModId,
+    ) -> Result<delete::UntrackStatus, delete::DeleteModError>;
+
+    async fn endorsements(&self) -> Result<Endorsements, validate::ValidateError>;
+
+    async fn updated_during(
+        &self,
+        game: &str,
+        time: TimePeriod,
+    ) -> Result<Vec<ModUpdated>, get::GameModError>;
+
+    async fn changelogs(&self, game: &str, id: ModId) -> Result<Changelog, get::GameModError>;
+
+    async fn mod_info(&self, game: &str, id: ModId) -> Result<GameMod, get::GameModError>;
+
+    async fn games(&self) -> Result<Vec<GameId>, get::GameModError>;
+
+    async fn game(&self, game: &str) -> Result<GameId, get::GameModError>;
+
+    async fn mod_files(
+        &self,
+        game: &str,
+        mod_id: ModId,
+        category: Option<CategoryName>,
+    ) -> Result<ModFiles, get::GameModError>;
+
+    async fn mod_file(
+        &self,
+        game: &str,
+        mod_id: ModId,
+        file_id: u64,
+    ) -> Result<ModFile, get::GameModError>;
+
+    async fn download_link(
+        &self,
+        game: &str,
+        mod_id: ModId,
+        file_id: u64,
+        nxm: Option<NxmParams>,
+    ) -> Result<Vec<DownloadLink>, get::GameModError>;
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl NexusApi for Api {
+    async fn validate(&self) -> Result<Validate, validate::ValidateError> {
+        Api::validate(self).await
+    }
+
+    async fn tracked_mods(&self) -> Result<TrackedModsRaw, validate::ValidateError> {
+        Api::tracked_mods(self).await
+    }
+
+    async fn track_mod(
+        &self,
+        game: &str,
+        id: u64,
+    ) -> Result<post::PostModStatus, post::TrackModError> {
+        Api::track_mod(self, game, id).await
+    }
+
+    async fn untrack_mod(
+        &self,
+        game: &str,
+        id: ModId,
+    ) -> Result<delete::UntrackStatus, delete::DeleteModError> {
+        Api::untrack_mod(self, game, id).await
+    }
+
+    async fn endorsements(&self) -> Result<Endorsements, validate::ValidateError> {
+        Api::endorsements(self).await
+    }
+
+    async fn updated_during(
+        &self,
+        game: &str,
+        time: TimePeriod,
+    ) -> Result<Vec<ModUpdated>, get::GameModError> {
+        Api::updated_during(self, game, time).await
+    }
+
+    async fn changelogs(&self, game: &str, id: ModId) -> Result<Changelog, get::GameModError> {
+        Api::changelogs(self, game, id).await
+    }
+
+    async fn mod_info(&self, game: &str, id: ModId) -> Result<GameMod, get::GameModError> {
+        Api::mod_info(self, game, id).await
+    }
+
+    async fn games(&self) -> Result<Vec<GameId>, get::GameModError> {
+        Api::games(self).await
+    }
+
+    async fn game(&self, game: &str) -> Result<GameId, get::GameModError> {
+        Api::game(self, game).await
+    }
+
+    async fn mod_files(
+        &self,
+        game: &str,
+        mod_id: ModId,
+        category: Option<CategoryName>,
+    ) -> Result<ModFiles, get::GameModError> {
+        Api::mod_files(self, game, mod_id, category).await
+    }
+
+    async fn mod_file(
+        &self,
+        game: &str,
+        mod_id: ModId,
+        file_id: u64,
+    ) -> Result<ModFile, get::GameModError> {
+        Api::mod_file(self, game, mod_id, file_id).await
+    }
+
+    async fn download_link(
+        &self,
+        game: &str,
+        mod_id: ModId,
+        file_id: u64,
+        nxm: Option<NxmParams>,
+    ) -> Result<Vec<DownloadLink>, get::GameModError> {
+        Api::download_link(self, game, mod_id, file_id, nxm).await
+    }
+}
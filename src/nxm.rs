@@ -0,0 +1,147 @@
+//! Parsing for `nxm://` links produced by the "Mod Manager Download" button.
+
+use reqwest::Url;
+
+use crate::err::NxmParseError;
+
+/// A parsed `nxm://` link.
+///
+/// # Examples
+///
+/// ```
+/// # use cyclone_mod::nxm::NxmLink;
+/// let link = NxmLink::parse(
+///     "nxm://skyrimspecialedition/mods/12345/files/67890?key=abc&expires=123&user_id=42",
+/// )
+/// .unwrap();
+/// assert_eq!(link.game(), "skyrimspecialedition");
+/// assert_eq!(link.mod_id(), 12345);
+/// assert_eq!(link.file_id(), 67890);
+/// assert_eq!(link.key(), "abc");
+/// assert_eq!(link.expires(), 123);
+/// assert_eq!(link.user_id(), Some(42));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NxmLink {
+    game: String,
+    mod_id: u64,
+    file_id: u64,
+    key: String,
+    expires: u64,
+    user_id: Option<u64>,
+}
+
+impl NxmLink {
+    pub fn parse(link: &str) -> Result<Self, NxmParseError> {
+        let url = Url::parse(link)?;
+
+        if url.scheme() != "nxm" {
+            return Err(NxmParseError::WrongScheme(url.scheme().to_string()));
+        }
+
+        let game = match url.host_str() {
+            Some(game) if !game.is_empty() => game.to_string(),
+            _ => return Err(NxmParseError::MissingGame),
+        };
+
+        let segments = url
+            .path_segments()
+            .map(|s| s.collect::<Vec<_>>())
+            .unwrap_or_default();
+        let (mod_id, file_id) = match segments.as_slice() {
+            ["mods", mod_id, "files", file_id] => {
+                (number("mod_id", mod_id)?, number("file_id", file_id)?)
+            }
+            _ => return Err(NxmParseError::MalformedPath(url.path().to_string())),
+        };
+
+        let query = |name: &'static str| {
+            url.query_pairs()
+                .find(|(k, _)| k == name)
+                .map(|(_, v)| v.into_owned())
+        };
+
+        let key = query("key").ok_or(NxmParseError::MissingQuery("key"))?;
+        let expires = number(
+            "expires",
+            &query("expires").ok_or(NxmParseError::MissingQuery("expires"))?,
+        )?;
+        let user_id = query("user_id")
+            .map(|id| number("user_id", &id))
+            .transpose()?;
+
+        Ok(Self {
+            game,
+            mod_id,
+            file_id,
+            key,
+            expires,
+            user_id,
+        })
+    }
+
+    /// The game domain name.
+    pub fn game(&self) -> &str {
+        &self.game
+    }
+
+    /// Unverified mod ID.
+    pub const fn mod_id(&self) -> u64 {
+        self.mod_id
+    }
+
+    pub const fn file_id(&self) -> u64 {
+        self.file_id
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Unix timestamp after which [`NxmLink::key`] is no longer accepted.
+    pub const fn expires(&self) -> u64 {
+        self.expires
+    }
+
+    pub const fn user_id(&self) -> Option<u64> {
+        self.user_id
+    }
+
+    /// Strip the link down to what a non-premium download needs.
+    pub fn into_download_params(self) -> NxmParams {
+        NxmParams {
+            key: self.key,
+            expires: self.expires,
+        }
+    }
+}
+
+/// The `key` and `expires` pair that lets non-premium users generate download links.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NxmParams {
+    key: String,
+    expires: u64,
+}
+
+impl NxmParams {
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub const fn expires(&self) -> u64 {
+        self.expires
+    }
+}
+
+impl From<NxmLink> for NxmParams {
+    fn from(value: NxmLink) -> Self {
+        value.into_download_params()
+    }
+}
+
+fn number(field: &'static str, value: &str) -> Result<u64, NxmParseError> {
+    value.parse().map_err(|_| NxmParseError::InvalidNumber {
+        field,
+        value: value.to_string(),
+    })
+}
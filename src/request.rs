@@ -1,42 +1,84 @@
-use std::{collections::HashMap, fmt::Display, ops::Deref, path::PathBuf, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    ops::Deref,
+    path::PathBuf,
+    time::Duration,
+};
 
-use reqwest::Url;
+use reqwest::{StatusCode, Url, header::HeaderMap};
 use serde::{
     Deserialize, Serialize,
     de::{self, Visitor},
 };
-use time::{OffsetDateTime, UtcDateTime};
+use time::{OffsetDateTime, UtcDateTime, format_description::well_known::Iso8601};
+
+use crate::{
+    VERSION,
+    err::{ColourError, InvalidGameDomain},
+};
 
+/// Panicking shorthand for [`build_url`].
+///
+/// Like [`build_url`], this always points at the production API.
+///
+/// ```
+/// let url = cyclone_mod::nexus_joiner!("v1", ["games", "some game", "mods", "a?b#c"]);
+/// assert_eq!(
+///     url.as_str(),
+///     "https://api.nexusmods.com/v1/games/some%20game/mods/a%3Fb%23c.json",
+/// );
+/// ```
 #[macro_export]
 macro_rules! nexus_joiner {
     ($ver:expr, $components:expr) => {{
-        let mut url = reqwest::Url::parse("https://api.nexusmods.com")
-            .expect("Could not parse URL (very fatal!)")
-            .join(&format!("{}/", $ver))
-            .expect("Could not join version!");
-        let mut it = $components.into_iter().peekable();
-        while let Some(comp) = it.next() {
-            if it.peek().is_none() {
-                url = url
-                    .join(&format!("{}.json", comp))
-                    .expect("Could not join {comp}");
-            } else {
-                url = url
-                    .join(&format!("{}/", comp))
-                    .expect("Could not join {comp}");
-            }
-        }
-        url
+        $crate::request::build_url($ver, &$components)
+            .unwrap_or_else(|e| panic!("Could not build URL: {e}"))
     }};
 }
 
-#[derive(Clone, Copy)]
+/// Where the production Nexus API lives.
+pub(crate) const BASE_URL: &str = "https://api.nexusmods.com";
+
+/// Build a production API URL out of a version and path components, adding `.json` to the last
+/// one.
+///
+/// Each component is percent-encoded, so it can't escape its path segment.
+pub fn build_url(ver: &str, components: &[&str]) -> Result<Url, url::ParseError> {
+    build_url_on(&Url::parse(BASE_URL)?, ver, components)
+}
+
+/// Like [`build_url`], but against another instance of the API, such as a staging server.
+///
+/// Any path `base` already has is kept in front of the version.
+///
+/// ```
+/// # use cyclone_mod::request::build_url_on;
+/// let base = "https://staging.example.com/api/".parse().unwrap();
+/// let url = build_url_on(&base, "v1", &["games", "skyrim"]).unwrap();
+/// assert_eq!(url.as_str(), "https://staging.example.com/api/v1/games/skyrim.json");
+/// ```
+pub fn build_url_on(base: &Url, ver: &str, components: &[&str]) -> Result<Url, url::ParseError> {
+    let mut url = base.clone();
+    {
+        let mut segments = url
+            .path_segments_mut()
+            .map_err(|()| url::ParseError::RelativeUrlWithCannotBeABaseBase)?;
+        segments.pop_if_empty().push(ver);
+        if let Some((last, rest)) = components.split_last() {
+            segments.extend(rest).push(&format!("{last}.json"));
+        }
+    }
+    Ok(url)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Limited {
     Hourly,
     Daily,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct RateLimiting {
     // Limited to 2,500 requests per 24 hours.
     pub(crate) hourly_limit: u16,
@@ -49,6 +91,28 @@ pub struct RateLimiting {
 }
 
 impl RateLimiting {
+    /// Parse the `x-rl-*` headers Nexus attaches to every response.
+    ///
+    /// Returns [`None`] if any of them are missing or malformed.
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        fn num(headers: &HeaderMap, name: &str) -> Option<u16> {
+            headers.get(name)?.to_str().ok()?.parse().ok()
+        }
+
+        fn date(headers: &HeaderMap, name: &str) -> Option<OffsetDateTime> {
+            OffsetDateTime::parse(headers.get(name)?.to_str().ok()?, &Iso8601::DEFAULT).ok()
+        }
+
+        Some(Self {
+            hourly_limit: num(headers, "x-rl-hourly-limit")?,
+            hourly_remaining: num(headers, "x-rl-hourly-remaining")?,
+            hourly_reset: date(headers, "x-rl-hourly-reset")?,
+            daily_limit: num(headers, "x-rl-daily-limit")?,
+            daily_remaining: num(headers, "x-rl-daily-remaining")?,
+            daily_reset: date(headers, "x-rl-daily-reset")?,
+        })
+    }
+
     pub const fn limit(&self, limit: Limited) -> u16 {
         match limit {
             Limited::Hourly => self.hourly_limit,
@@ -69,17 +133,92 @@ impl RateLimiting {
             Limited::Daily => self.daily_reset.to_utc(),
         }
     }
+
+    /// Has either the hourly or daily limit been used up?
+    pub const fn is_exhausted(&self) -> bool {
+        self.hourly_remaining == 0 || self.daily_remaining == 0
+    }
+
+    /// How long until a given limit resets.
+    ///
+    /// If the reset time has already passed, this is [`Duration::ZERO`].
+    pub fn time_until_reset(&self, limit: Limited) -> Duration {
+        (self.reset(limit) - UtcDateTime::now())
+            .try_into()
+            .unwrap_or(Duration::ZERO)
+    }
+}
+
+/// Details of the most recent response, for debugging latency or routing issues.
+///
+/// See [`Api::last_response_meta`](`crate::Api::last_response_meta`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LastResponseMeta {
+    status: StatusCode,
+    headers: HeaderMap,
+    elapsed: Duration,
+}
+
+impl LastResponseMeta {
+    pub(crate) fn new(status: StatusCode, headers: &HeaderMap, elapsed: Duration) -> Self {
+        let mut headers = headers.clone();
+        headers.remove("apikey");
+        Self {
+            status,
+            headers,
+            elapsed,
+        }
+    }
+
+    pub const fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Response headers, such as `x-runtime`. Never contains the API key.
+    pub const fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Time from sending the request to receiving the response headers.
+    pub const fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
 }
 
 /// Validation object for a given user.
-#[derive(Debug, Serialize, Deserialize)]
+///
+/// # Examples
+///
+/// ```
+/// # use cyclone_mod::request::Validate;
+/// let body = r#"{
+///     "user_id": 1234567,
+///     "key": "abc",
+///     "name": "Someone",
+///     "is_premium?": true,
+///     "is_supporter?": true,
+///     "email": "someone@example.com",
+///     "profile_url": "https://avatars.nexusmods.com/1234567/100",
+///     "is_supporter": true,
+///     "is_premium": true
+/// }"#;
+/// let validate = Validate::from_json(body).unwrap();
+/// assert!(validate.is_premium());
+/// assert!(validate.is_supporter());
+/// ```
+#[derive(PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct Validate {
     user_id: usize,
     key: String,
     name: String,
-    #[serde(alias = "is_premium?")]
+    // Legacy spellings of `is_premium`/`is_supporter` with the same values. Only the unsuffixed
+    // fields are read, these are kept so serializing gives back what Nexus sent.
+    #[serde(rename = "is_premium?", default)]
+    #[cfg_attr(not(feature = "serialize"), allow(dead_code))]
     is_premium_q: bool,
-    #[serde(alias = "is_supporter?")]
+    #[serde(rename = "is_supporter?", default)]
+    #[cfg_attr(not(feature = "serialize"), allow(dead_code))]
     is_supporter_q: bool,
     email: String,
     profile_url: Url,
@@ -87,11 +226,30 @@ pub struct Validate {
     is_supporter: bool,
 }
 
+impl std::fmt::Debug for Validate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Validate")
+            .field("user_id", &self.user_id)
+            .field("key", &"***")
+            .field("name", &self.name)
+            .field("email", &self.email)
+            .field("profile_url", &self.profile_url)
+            .field("is_premium", &self.is_premium)
+            .field("is_supporter", &self.is_supporter)
+            .finish_non_exhaustive()
+    }
+}
+
 impl Validate {
+    /// Parse a response saved from [`Api::validate`](`crate::Api::validate`), such as for
+    /// testing offline.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
     /// Is the user a premium user?
     pub const fn is_premium(&self) -> bool {
-        // I think?
-        self.is_premium_q && self.is_premium
+        self.is_premium
     }
 
     /// Is the user a supporter?
@@ -99,8 +257,7 @@ impl Validate {
     /// In order for this to be `true`, the user must've bought premium at any point in time, even
     /// if they currently do not have it.
     pub const fn is_supporter(&self) -> bool {
-        // I think?
-        self.is_supporter_q && self.is_supporter
+        self.is_supporter
     }
 
     pub fn email(&self) -> &str {
@@ -118,12 +275,43 @@ impl Validate {
     pub fn url(&self) -> &Url {
         &self.profile_url
     }
+
+    /// The API key as Nexus accepted it.
+    ///
+    /// Store this rather than what the user typed in, which may carry stray whitespace.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Is `sent` exactly the key Nexus accepted?
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclone_mod::request::Validate;
+    /// let validate = Validate::from_json(r#"{
+    ///     "user_id": 1, "key": "abc", "name": "Someone", "email": "someone@example.com",
+    ///     "profile_url": "https://avatars.nexusmods.com/1/100",
+    ///     "is_supporter": false, "is_premium": false
+    /// }"#).unwrap();
+    /// assert!(validate.matches_key("abc"));
+    /// assert!(!validate.matches_key("abc\n"));
+    /// ```
+    pub fn matches_key(&self, sent: &str) -> bool {
+        self.key == sent
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct ModEntry {
     mod_id: ModId,
     domain_name: String,
+    // Only sent in some responses.
+    #[serde(default)]
+    category_id: Option<u64>,
+    #[serde(default, with = "ts")]
+    tracked_timestamp: Option<OffsetDateTime>,
 }
 
 impl ModEntry {
@@ -134,18 +322,89 @@ impl ModEntry {
     pub fn domain_name(&self) -> &str {
         &self.domain_name
     }
+
+    /// The mod's category within its game, if Nexus sent it.
+    pub const fn category_id(&self) -> Option<u64> {
+        self.category_id
+    }
+
+    /// When the user started tracking the mod, if Nexus sent it.
+    pub const fn tracked_since(&self) -> Option<UtcDateTime> {
+        match self.tracked_timestamp {
+            Some(ts) => Some(ts.to_utc()),
+            None => None,
+        }
+    }
 }
 
 /// A checked and verified-to-exist mod ID.
 ///
 /// A thin wrapper for a `u64`, but everywhere that you see [`ModId`], you can assume
 /// that it is a valid mod ID, as opposed to a random number which may or may not exist.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+///
+/// Nexus sends mod IDs as numbers from some endpoints and as strings from others, so both
+/// deserialize:
+///
+/// ```
+/// # use cyclone_mod::request::ModId;
+/// let number: ModId = serde_json::from_str("123").unwrap();
+/// let string: ModId = serde_json::from_str(r#""123""#).unwrap();
+/// assert_eq!(number, string);
+/// assert_eq!(number, 123);
+/// assert!(serde_json::from_str::<ModId>(r#""12a""#).is_err());
+/// assert!(serde_json::from_str::<ModId>("-1").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
 #[serde(transparent)]
 pub struct ModId {
     id: u64,
 }
 
+impl<'de> Deserialize<'de> for ModId {
+    fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct ModIdVisitor;
+
+        impl Visitor<'_> for ModIdVisitor {
+            type Value = ModId;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "a mod ID as a number or a string of digits")
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(ModId::from_u64(v))
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                u64::try_from(v)
+                    .map(ModId::from_u64)
+                    .map_err(|_| de::Error::custom("negative number not allowed"))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                if v.is_empty() || !v.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(de::Error::invalid_value(de::Unexpected::Str(v), &self));
+                }
+                v.parse().map(ModId::from_u64).map_err(de::Error::custom)
+            }
+        }
+
+        de.deserialize_any(ModIdVisitor)
+    }
+}
+
 impl ModId {
     /// Get the underlying `u64`.
     pub const fn id(&self) -> u64 {
@@ -157,6 +416,50 @@ impl ModId {
     pub(crate) const fn from_u64(id: u64) -> Self {
         Self { id }
     }
+
+    /// The mod's page on the Nexus website. See [`mod_page_url`].
+    pub fn page_url(&self, game: &str) -> Url {
+        mod_page_url(game, self.id)
+    }
+
+    /// The API endpoint describing this mod, as used by [`Api::mod_info`](`crate::Api::mod_info`).
+    ///
+    /// This always points at the production API, even for an [`Api`](`crate::Api`) built with
+    /// [`ApiBuilder::base_url`](`crate::ApiBuilder::base_url`).
+    ///
+    /// ```
+    /// # use cyclone_mod::request::ModId;
+    /// # let id: ModId = serde_json::from_str("266").unwrap();
+    /// assert_eq!(
+    ///     id.api_url("skyrimspecialedition").unwrap().as_str(),
+    ///     "https://api.nexusmods.com/v1/games/skyrimspecialedition/mods/266.json",
+    /// );
+    /// ```
+    pub fn api_url(&self, game: &str) -> Result<Url, url::ParseError> {
+        build_url(
+            VERSION,
+            &["games", game, "mods", self.id.to_string().as_str()],
+        )
+    }
+}
+
+/// The page on the Nexus website for mod `id` of `game`, for linking users back to it.
+///
+/// ```
+/// use cyclone_mod::request::mod_page_url;
+///
+/// assert_eq!(
+///     mod_page_url("skyrimspecialedition", 266).as_str(),
+///     "https://www.nexusmods.com/skyrimspecialedition/mods/266",
+/// );
+/// ```
+pub fn mod_page_url(game: &str, id: u64) -> Url {
+    let mut url =
+        Url::parse("https://www.nexusmods.com").expect("Could not parse URL (very fatal!)");
+    url.path_segments_mut()
+        .expect("https URLs always have a path")
+        .extend([game, "mods", id.to_string().as_str()]);
+    url
 }
 
 impl PartialEq<u64> for ModId {
@@ -178,7 +481,8 @@ impl From<ModEntry> for ModId {
 }
 
 /// You may find this to be very tedious to work with. Consider [`TrackedMods`] instead.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[serde(transparent)]
 pub struct TrackedModsRaw {
     mods: Vec<ModEntry>,
@@ -190,6 +494,15 @@ impl TrackedModsRaw {
     }
 }
 
+impl IntoIterator for TrackedModsRaw {
+    type Item = ModEntry;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.mods.into_iter()
+    }
+}
+
 impl From<TrackedModsRaw> for TrackedMods {
     fn from(value: TrackedModsRaw) -> Self {
         let mut mods: HashMap<String, Vec<ModId>> = HashMap::with_capacity(value.mods.len());
@@ -203,7 +516,7 @@ impl From<TrackedModsRaw> for TrackedMods {
 }
 
 /// A collection of game names and tracked mod IDs.
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub struct TrackedMods {
     mods: HashMap<String, Vec<ModId>>,
 }
@@ -218,6 +531,16 @@ impl TrackedMods {
     pub fn games(&self) -> impl Iterator<Item = &str> {
         self.mods.keys().map(String::as_str)
     }
+
+    /// Like [`TrackedMods::games`], but owned.
+    pub fn into_games(self) -> Vec<String> {
+        self.mods.into_keys().collect()
+    }
+
+    /// Like [`TrackedMods::get_game`], but owned.
+    pub fn into_game(mut self, name: &str) -> Option<Vec<ModId>> {
+        self.mods.remove(name)
+    }
 }
 
 impl IntoIterator for TrackedMods {
@@ -229,7 +552,8 @@ impl IntoIterator for TrackedMods {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[serde(transparent)]
 pub struct Endorsements {
     mods: Vec<Endorsement>,
@@ -251,9 +575,17 @@ impl Endorsements {
     {
         self.mods.iter().find(|e| func(e))
     }
+
+    /// Every endorsement, most recent first.
+    pub fn sorted_by_date(&self) -> Vec<&Endorsement> {
+        let mut mods: Vec<_> = self.mods.iter().collect();
+        mods.sort_by_key(|e| std::cmp::Reverse(e.date));
+        mods
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct Endorsement {
     mod_id: ModId,
     domain_name: String,
@@ -276,6 +608,39 @@ impl Endorsement {
         self.version.as_deref()
     }
 
+    /// Did Nexus record which version of the mod was endorsed?
+    pub const fn is_versioned(&self) -> bool {
+        self.version.is_some()
+    }
+
+    /// Was an older version than `current` endorsed?
+    ///
+    /// Versions are compared numerically part by part where possible, so `1.9` is older than
+    /// `1.10`. Endorsements without a version are never stale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclone_mod::request::Endorsements;
+    /// let endorsements: Endorsements = serde_json::from_str(r#"[{
+    ///     "mod_id": 266,
+    ///     "domain_name": "skyrimspecialedition",
+    ///     "date": "2024-01-01T00:00:00.000+00:00",
+    ///     "version": "1.9",
+    ///     "status": "Endorsed"
+    /// }]"#).unwrap();
+    /// let endorsement = endorsements.find(|_| true).unwrap();
+    ///
+    /// assert!(endorsement.is_versioned());
+    /// assert!(endorsement.is_stale("1.10"));
+    /// assert!(!endorsement.is_stale("1.9"));
+    /// ```
+    pub fn is_stale(&self, current: &str) -> bool {
+        self.version
+            .as_deref()
+            .is_some_and(|version| compare_versions(version, current).is_lt())
+    }
+
     pub const fn date(&self) -> UtcDateTime {
         self.date.to_utc()
     }
@@ -287,16 +652,41 @@ impl Endorsement {
     pub const fn is_endorsed(&self) -> bool {
         matches!(self.status, EndorseStatus::Endorsed)
     }
+
+    /// The game, mod, and version to pass to [`Api::endorse`](`crate::Api::endorse`) to endorse
+    /// this mod again, such as on a new machine.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cyclone_mod::{Api, Error};
+    /// # async fn run(api: Api) -> Result<(), Error> {
+    /// for endorsement in api.endorsements().await?.into_iter().filter(|e| e.is_endorsed()) {
+    ///     let (game, id, version) = endorsement.endorse_args();
+    ///     api.endorse(&game, id, version.as_deref()).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn endorse_args(&self) -> (String, ModId, Option<String>) {
+        (self.domain_name.clone(), self.mod_id, self.version.clone())
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub enum EndorseStatus {
     Endorsed,
+    /// The user actively declined to endorse.
+    Abstained,
+    /// The user has neither endorsed nor abstained.
+    Undecided,
     #[serde(untagged)]
     NotEndorsed,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct GameId {
     id: u64,
     name: String,
@@ -305,16 +695,26 @@ pub struct GameId {
     genre: String,
     file_count: u64,
     domain_name: String,
-    #[serde(with = "time::serde::timestamp")]
-    approved_date: OffsetDateTime,
+    /// Games still awaiting approval send `0` or `null`.
+    #[serde(default, with = "ts")]
+    approved_date: Option<OffsetDateTime>,
     file_views: u64,
     authors: u64,
     file_endorsements: u64,
     mods: u64,
+    /// New games may not have any categories yet, and Nexus sends `null` or leaves the field
+    /// out.
+    #[serde(default, deserialize_with = "null_as_default::deserialize")]
     categories: Vec<GameCategory>,
 }
 
 impl GameId {
+    /// Parse a response saved from [`Api::game`](`crate::Api::game`), such as for testing
+    /// offline.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
     pub const fn id(&self) -> u64 {
         self.id
     }
@@ -339,8 +739,19 @@ impl GameId {
         &self.domain_name
     }
 
-    pub const fn approved_date(&self) -> UtcDateTime {
-        self.approved_date.to_utc()
+    /// When the game was approved, or [`None`] if it hasn't been yet.
+    pub const fn approved_date(&self) -> Option<UtcDateTime> {
+        match self.approved_date {
+            Some(date) => Some(date.to_utc()),
+            None => None,
+        }
+    }
+
+    /// Has the game been approved by Nexus?
+    ///
+    /// Only [`Api::games_filtered`](`crate::Api::games_filtered`) returns unapproved games.
+    pub const fn is_approved(&self) -> bool {
+        self.approved_date.is_some()
     }
 
     pub const fn file_views(&self) -> u64 {
@@ -359,6 +770,23 @@ impl GameId {
         self.mods
     }
 
+    pub const fn file_count(&self) -> u64 {
+        self.file_count
+    }
+
+    /// Every count Nexus reports for this game, in one place.
+    pub const fn stats(&self) -> GameStats {
+        GameStats {
+            mods: self.mods,
+            files: self.file_count,
+            file_views: self.file_views,
+            authors: self.authors,
+            endorsements: self.file_endorsements,
+        }
+    }
+
+    /// Every category of the game. Empty for games too new to have any, for which Nexus sends
+    /// `null` or leaves the field out.
     pub fn categories(&self) -> &[GameCategory] {
         &self.categories
     }
@@ -371,16 +799,186 @@ impl GameId {
             Category::None => false,
         })
     }
+
+    /// Categories without a parent.
+    pub fn root_categories(&self) -> impl Iterator<Item = &GameCategory> {
+        self.categories
+            .iter()
+            .filter(|cat| matches!(cat.parent_category, Category::None))
+    }
+
+    /// Categories whose parent is the category with ID `id`.
+    pub fn children_of(&self, id: u64) -> impl Iterator<Item = &GameCategory> {
+        self.categories
+            .iter()
+            .filter(move |cat| matches!(cat.parent_category, Category::Category(n) if n == id))
+    }
+}
+
+impl Display for GameId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.name, self.domain_name)
+    }
+}
+
+/// The domain name Nexus identifies a game by, such as `skyrimspecialedition`, checked to be
+/// well formed.
+///
+/// # Examples
+///
+/// ```
+/// # use cyclone_mod::request::GameDomain;
+/// let skyrim: GameDomain = "skyrimspecialedition".parse().unwrap();
+/// assert_eq!(skyrim.as_str(), "skyrimspecialedition");
+/// assert!(GameDomain::new("Skyrim Special Edition").is_err());
+/// assert!(GameDomain::new("../users").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "serialize", serde(transparent))]
+pub struct GameDomain(String);
+
+impl GameDomain {
+    /// Check that `domain` only contains lowercase letters, digits, `-`, and `_`, like every
+    /// domain name Nexus hands out.
+    pub fn new(domain: &str) -> Result<Self, InvalidGameDomain> {
+        let valid = !domain.is_empty()
+            && domain
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+
+        if valid {
+            Ok(Self(domain.to_string()))
+        } else {
+            Err(InvalidGameDomain(domain.to_string()))
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::str::FromStr for GameDomain {
+    type Err = InvalidGameDomain;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+/// Nexus' own domain names are trusted as is.
+impl From<&GameId> for GameDomain {
+    fn from(game: &GameId) -> Self {
+        Self(game.domain_name.clone())
+    }
+}
+
+impl Deref for GameDomain {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for GameDomain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// See [`GameId::stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct GameStats {
+    pub mods: u64,
+    pub files: u64,
+    pub file_views: u64,
+    pub authors: u64,
+    pub endorsements: u64,
+}
+
+/// The list from [`Api::games`](`crate::Api::games`), with ways to group and rank it.
+///
+/// Build one with `Games::from(api.games().await?)`.
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[serde(transparent)]
+pub struct Games {
+    games: Vec<GameId>,
+}
+
+impl Games {
+    /// Games grouped by [`GameId::genre`].
+    pub fn by_genre(&self) -> HashMap<&str, Vec<&GameId>> {
+        let mut genres: HashMap<&str, Vec<&GameId>> = HashMap::new();
+        for game in &self.games {
+            genres.entry(game.genre()).or_default().push(game);
+        }
+        genres
+    }
+
+    /// The `n` games with the most mods, most first.
+    pub fn most_mods(&self, n: usize) -> Vec<&GameId> {
+        let mut games: Vec<&GameId> = self.games.iter().collect();
+        games.sort_by_key(|game| std::cmp::Reverse(game.mods));
+        games.truncate(n);
+        games
+    }
+
+    /// Like [`Deref`], but owned.
+    pub fn into_inner(self) -> Vec<GameId> {
+        self.games
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl From<Vec<GameId>> for Games {
+    fn from(games: Vec<GameId>) -> Self {
+        Self { games }
+    }
+}
+
+impl Deref for Games {
+    type Target = [GameId];
+
+    fn deref(&self) -> &Self::Target {
+        &self.games
+    }
+}
+
+impl IntoIterator for Games {
+    type Item = GameId;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.games.into_iter()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct GameCategory {
     category_id: u64,
     name: String,
     parent_category: Category,
 }
 
-#[derive(Debug)]
+impl GameCategory {
+    pub const fn id(&self) -> u64 {
+        self.category_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub const fn parent(&self) -> &Category {
+        &self.parent_category
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
 pub enum Category {
     Category(u64),
     None,
@@ -434,6 +1032,7 @@ impl<'de> Deserialize<'de> for Category {
     }
 }
 
+#[cfg(feature = "serialize")]
 impl Serialize for Category {
     fn serialize<S>(&self, se: S) -> Result<S::Ok, S::Error>
     where
@@ -446,13 +1045,32 @@ impl Serialize for Category {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct ModFiles {
     files: Vec<ModFile>,
     file_updates: Vec<FileUpdate>,
 }
 
 impl ModFiles {
+    /// Parse a response saved from [`Api::mod_files`](`crate::Api::mod_files`), such as for
+    /// testing offline.
+    ///
+    /// Unlike files fetched through [`Api`](`crate::Api`), these don't know which game or mod they
+    /// belong to.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    pub(crate) fn with_context(mut self, game: &str, mod_id: ModId) -> Self {
+        self.files = self
+            .files
+            .into_iter()
+            .map(|file| file.with_context(game, mod_id))
+            .collect();
+        self
+    }
+
     pub fn iter_files(&self) -> impl Iterator<Item = &ModFile> {
         self.files.iter()
     }
@@ -469,6 +1087,132 @@ impl ModFiles {
         self.file_updates.into_iter()
     }
 
+    /// Follow the updates starting at `file_id` through to the newest file.
+    ///
+    /// Empty if `file_id` has never been superseded.
+    pub fn update_chain(&self, file_id: u64) -> Vec<&FileUpdate> {
+        let mut chain: Vec<&FileUpdate> = vec![];
+        let mut current = file_id;
+
+        while let Some(update) = self.file_updates.iter().find(|u| u.old_file_id == current) {
+            // Guard against Nexus ever sending a cycle.
+            if chain.iter().any(|u| u.old_file_id == update.new_file_id) {
+                break;
+            }
+            chain.push(update);
+            current = update.new_file_id;
+        }
+
+        chain
+    }
+
+    /// Is `file_id` the newest file of its update chain?
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclone_mod::request::ModFiles;
+    /// let update = |old: u64, new: u64| {
+    ///     format!(
+    ///         r#"{{"old_file_id": {old}, "new_file_id": {new}, "old_file_name": "a",
+    ///         "new_file_name": "b", "uploaded_timestamp": 0,
+    ///         "uploaded_time": "1970-01-01T00:00:00.000+00:00"}}"#
+    ///     )
+    /// };
+    /// let body = format!(
+    ///     r#"{{"files": [], "file_updates": [{}, {}, {}]}}"#,
+    ///     update(1, 2),
+    ///     update(2, 3),
+    ///     update(3, 4),
+    /// );
+    /// let files: ModFiles = serde_json::from_str(&body).unwrap();
+    ///
+    /// assert!(!files.is_latest(2));
+    /// assert!(files.is_latest(4));
+    /// assert_eq!(files.update_chain(2).len(), 2);
+    /// ```
+    pub fn is_latest(&self, file_id: u64) -> bool {
+        !self.file_updates.iter().any(|u| u.old_file_id == file_id)
+    }
+
+    /// Is `file_id` current, superseded by a newer file, or unknown to this mod?
+    ///
+    /// A superseded file is reported as such even if it has since been removed from the file
+    /// list.
+    pub fn file_status(&self, file_id: u64) -> FileStatus {
+        if let Some(newest) = self.update_chain(file_id).last() {
+            FileStatus::Superseded {
+                latest: newest.new_file_id,
+            }
+        } else if self.files.iter().any(|file| file.file_id == file_id) {
+            FileStatus::Current
+        } else {
+            FileStatus::Missing
+        }
+    }
+
+    /// What changed since an earlier snapshot of the same mod's files.
+    ///
+    /// Files are matched by [`ModFile::file_id`] and updates by [`FileUpdate::ids`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclone_mod::request::ModFiles;
+    /// let update = |old: u64, new: u64| {
+    ///     format!(
+    ///         r#"{{"old_file_id": {old}, "new_file_id": {new}, "old_file_name": "a",
+    ///         "new_file_name": "b", "uploaded_timestamp": 0,
+    ///         "uploaded_time": "1970-01-01T00:00:00.000+00:00"}}"#
+    ///     )
+    /// };
+    /// let before = ModFiles::from_json(&format!(
+    ///     r#"{{"files": [], "file_updates": [{}]}}"#,
+    ///     update(1, 2),
+    /// ))
+    /// .unwrap();
+    /// let after = ModFiles::from_json(&format!(
+    ///     r#"{{"files": [], "file_updates": [{}, {}]}}"#,
+    ///     update(1, 2),
+    ///     update(2, 3),
+    /// ))
+    /// .unwrap();
+    ///
+    /// let diff = after.diff(&before);
+    /// assert_eq!(diff.updated.len(), 1);
+    /// assert_eq!(diff.updated[0].ids(), (2, 3));
+    /// assert!(after.diff(&after).is_empty());
+    /// ```
+    pub fn diff<'a>(&'a self, previous: &ModFiles) -> FilesDiff<'a> {
+        let added = self
+            .files
+            .iter()
+            .filter(|file| !previous.files.iter().any(|f| f.file_id == file.file_id))
+            .collect();
+        let removed = previous
+            .files
+            .iter()
+            .filter(|file| !self.files.iter().any(|f| f.file_id == file.file_id))
+            .map(|file| file.file_id)
+            .collect();
+        let updated = self
+            .file_updates
+            .iter()
+            .filter(|update| {
+                !previous
+                    .file_updates
+                    .iter()
+                    .any(|u| u.ids() == update.ids())
+            })
+            .collect();
+
+        FilesDiff {
+            added,
+            removed,
+            updated,
+        }
+    }
+
     /// Deduplicate entries based on a condition.
     ///
     /// Mostly useful for when you want to just get a single throwaway instance of [`ModFile`],
@@ -491,9 +1235,24 @@ impl ModFiles {
 
         out
     }
+
+    /// Every category that at least one file is in, without duplicates.
+    ///
+    /// Ordered the same as the tabs on the Nexus website: main files first, archived files last.
+    pub fn categories(&self) -> Vec<CategoryName> {
+        let mut categories: Vec<CategoryName> = vec![];
+        for file in &self.files {
+            if !categories.contains(&file.category_name) {
+                categories.push(file.category_name.clone());
+            }
+        }
+        categories.sort();
+        categories
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct ModFile {
     id: Vec<u64>,
     uid: u64,
@@ -505,10 +1264,12 @@ pub struct ModFile {
     is_primary: bool,
     size: u64,
     file_name: String,
-    #[serde(with = "time::serde::timestamp")]
-    uploaded_timestamp: OffsetDateTime,
-    #[serde(with = "time::serde::iso8601")]
-    uploaded_time: OffsetDateTime,
+    #[serde(default, with = "ts")]
+    uploaded_timestamp: Option<OffsetDateTime>,
+    // Only read when serializing, `uploaded_at` goes by the timestamp.
+    #[serde(default, with = "time::serde::iso8601::option")]
+    #[cfg_attr(not(feature = "serialize"), allow(dead_code))]
+    uploaded_time: Option<OffsetDateTime>,
     mod_version: String,
     external_virus_scan_url: Option<Url>,
     description: Option<String>,
@@ -516,17 +1277,33 @@ pub struct ModFile {
     size_in_bytes: u64,
     changelog_html: Option<String>,
     content_preview_link: Url,
+    /// Filled in by [`Api`](`crate::Api`) since Nexus doesn't include it in the file itself.
+    #[serde(skip)]
+    game: Option<String>,
+    /// Filled in by [`Api`](`crate::Api`) since Nexus doesn't include it in the file itself.
+    #[serde(skip)]
+    mod_id: Option<ModId>,
 }
 
 impl ModFile {
+    /// The composite key Nexus uses for the file, in practice `[file_id, game_id]`.
+    ///
+    /// You almost always want [`ModFile::file_id`] instead.
     pub fn ids(&self) -> &[u64] {
         &self.id
     }
 
+    /// Does [`ModFile::ids`] contain `id`?
+    pub fn has_id(&self, id: u64) -> bool {
+        self.id.contains(&id)
+    }
+
+    /// An ID that is unique across every game, unlike [`ModFile::file_id`].
     pub const fn uid(&self) -> u64 {
         self.uid
     }
 
+    /// The ID of the file within its game, as used by [`Api::mod_file`](`crate::Api::mod_file`).
     pub const fn file_id(&self) -> u64 {
         self.file_id
     }
@@ -543,8 +1320,8 @@ impl ModFile {
         self.category_id
     }
 
-    pub const fn category_name(&self) -> CategoryName {
-        self.category_name
+    pub const fn category_name(&self) -> &CategoryName {
+        &self.category_name
     }
 
     pub const fn is_primary(&self) -> bool {
@@ -552,6 +1329,9 @@ impl ModFile {
     }
 
     /// Appears to be in kilobytes.
+    ///
+    /// Prefer [`ModFile::size_bytes`], which is the authoritative size.
+    // note: same value as `size_kb`, Nexus just sends it twice.
     pub const fn size(&self) -> u64 {
         self.size
     }
@@ -560,8 +1340,14 @@ impl ModFile {
         &self.file_name
     }
 
-    pub const fn uploaded_at(&self) -> UtcDateTime {
-        self.uploaded_timestamp.to_utc()
+    /// When the file was uploaded.
+    ///
+    /// Files still under moderation may not have an upload time yet.
+    pub const fn uploaded_at(&self) -> Option<UtcDateTime> {
+        match self.uploaded_timestamp {
+            Some(ts) => Some(ts.to_utc()),
+            None => None,
+        }
     }
 
     pub fn mod_version(&self) -> &str {
@@ -572,6 +1358,17 @@ impl ModFile {
         self.external_virus_scan_url.as_ref()
     }
 
+    /// Whether the file has been sent to an external virus scanner.
+    ///
+    /// Nexus only links to the report, so the verdict itself is not known here. See
+    /// [`Api::scan_verdict`](`crate::Api::scan_verdict`) for that.
+    pub fn virus_scan(&self) -> VirusScan<'_> {
+        match &self.external_virus_scan_url {
+            Some(url) => VirusScan::Scanned { url },
+            None => VirusScan::NotScanned,
+        }
+    }
+
     pub fn description(&self) -> Option<&str> {
         self.description.as_deref()
     }
@@ -580,10 +1377,35 @@ impl ModFile {
         self.size_kb
     }
 
+    /// Size of the file in bytes.
+    ///
+    /// This is the authoritative size; [`ModFile::size`] and [`ModFile::size_kb`] are rounded.
     pub const fn size_bytes(&self) -> u64 {
         self.size_in_bytes
     }
 
+    /// [`ModFile::size_bytes`] in the largest unit that keeps it above 1, such as `1.21 GB` or
+    /// `340 KB`.
+    ///
+    /// Units are powers of 1024, so 1023 bytes are `1023 B` and 1024 bytes are `1.00 KB`.
+    pub fn human_size(&self) -> String {
+        const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+
+        let mut size = self.size_in_bytes as f64;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+
+        match (unit, size) {
+            (0, _) => format!("{} {}", self.size_in_bytes, UNITS[0]),
+            (_, ..10.0) => format!("{size:.2} {}", UNITS[unit]),
+            (_, ..100.0) => format!("{size:.1} {}", UNITS[unit]),
+            _ => format!("{size:.0} {}", UNITS[unit]),
+        }
+    }
+
     pub fn changelog(&self) -> Option<&str> {
         self.changelog_html.as_deref()
     }
@@ -591,9 +1413,196 @@ impl ModFile {
     pub fn content_preview(&self) -> &Url {
         &self.content_preview_link
     }
+
+    /// Domain name of the game this file belongs to.
+    ///
+    /// Only known when the file was fetched through [`Api`](`crate::Api`).
+    pub fn game(&self) -> Option<&str> {
+        self.game.as_deref()
+    }
+
+    /// The mod this file belongs to.
+    ///
+    /// Only known when the file was fetched through [`Api`](`crate::Api`).
+    pub const fn mod_id(&self) -> Option<ModId> {
+        self.mod_id
+    }
+
+    pub(crate) fn with_context(mut self, game: &str, mod_id: ModId) -> Self {
+        self.game = Some(game.to_string());
+        self.mod_id = Some(mod_id);
+        self
+    }
+}
+
+/// See [`ModFiles::file_status`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub enum FileStatus {
+    /// The file is listed and nothing replaces it.
+    Current,
+    /// The file has been replaced, and `latest` is the end of its update chain.
+    Superseded { latest: u64 },
+    /// The mod has no file with this ID, for example because it was deleted.
+    Missing,
+}
+
+impl FileStatus {
+    pub const fn is_current(&self) -> bool {
+        matches!(self, Self::Current)
+    }
+}
+
+/// See [`ModFiles::diff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilesDiff<'a> {
+    /// Files that weren't there before.
+    pub added: Vec<&'a ModFile>,
+    /// IDs of files that are gone now.
+    pub removed: Vec<u64>,
+    /// Updates that weren't recorded before.
+    pub updated: Vec<&'a FileUpdate>,
+}
+
+impl FilesDiff<'_> {
+    /// Did nothing change?
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.updated.is_empty()
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// See [`ModFile::virus_scan`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VirusScan<'a> {
+    /// No scan report is linked, usually because the file is still being processed.
+    NotScanned,
+    /// A report exists at `url`.
+    Scanned { url: &'a Url },
+}
+
+impl VirusScan<'_> {
+    pub const fn is_scanned(&self) -> bool {
+        matches!(self, Self::Scanned { .. })
+    }
+}
+
+/// What a virus scan report says about a file, see
+/// [`Api::scan_verdict`](`crate::Api::scan_verdict`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub enum ScanVerdict {
+    /// No scanner flagged the file.
+    Clean,
+    /// `detections` scanners flagged the file.
+    Flagged { detections: u32 },
+    /// There is no report, or it couldn't be understood.
+    Unknown,
+}
+
+impl ScanVerdict {
+    /// Read the verdict out of a scan report page.
+    ///
+    /// # Warning
+    /// Reports are HTML pages made for people, not a stable format. This looks for either a
+    /// `"malicious": n` count or an `n / total` detection ratio directly followed by a word such
+    /// as "engines" or "security vendors", and gives up with [`ScanVerdict::Unknown`] otherwise,
+    /// so it may stop working whenever the scanner changes its page. Show the report itself from
+    /// [`ModFile::virus_scan_url`] when in doubt.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclone_mod::request::ScanVerdict;
+    /// assert_eq!(ScanVerdict::from_report(r#"{"malicious": 0}"#), ScanVerdict::Clean);
+    /// assert_eq!(
+    ///     ScanVerdict::from_report("<b>3 / 68</b> engines detected this file"),
+    ///     ScanVerdict::Flagged { detections: 3 },
+    /// );
+    /// assert_eq!(ScanVerdict::from_report("<html></html>"), ScanVerdict::Unknown);
+    ///
+    /// // Other numbers with a slash, such as dates, are not mistaken for detections.
+    /// assert_eq!(ScanVerdict::from_report("Scanned on 10/15/2026"), ScanVerdict::Unknown);
+    /// assert_eq!(
+    ///     ScanVerdict::from_report("Scanned on 10/15/2026: 0/68 security vendors flagged this file"),
+    ///     ScanVerdict::Clean,
+    /// );
+    /// ```
+    pub fn from_report(report: &str) -> Self {
+        let detections = malicious_count(report).or_else(|| detection_ratio(report));
+
+        match detections {
+            Some(0) => Self::Clean,
+            Some(detections) => Self::Flagged { detections },
+            None => Self::Unknown,
+        }
+    }
+
+    pub const fn is_clean(&self) -> bool {
+        matches!(self, Self::Clean)
+    }
+}
+
+/// The number after `"malicious":`.
+fn malicious_count(report: &str) -> Option<u32> {
+    let (_, rest) = report.split_once("\"malicious\":")?;
+    leading_number(rest.trim_start())
+}
+
+/// What scanners call themselves right after a detection ratio, as in "3 / 68 engines detected
+/// this file".
+const RATIO_MARKERS: [&str; 4] = ["engines", "security vendors", "scanners", "antivirus"];
+
+/// The `n` of the first `n / total` followed by one of [`RATIO_MARKERS`].
+fn detection_ratio(report: &str) -> Option<u32> {
+    report.match_indices('/').find_map(|(i, _)| {
+        let before = report[..i].trim_end();
+        let start = before.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+        let detections = before[start..].parse().ok()?;
+
+        let after = report[i + 1..].trim_start();
+        let total = after.trim_start_matches(|c: char| c.is_ascii_digit());
+        if total.len() == after.len() {
+            return None;
+        }
+
+        // The total is often wrapped in a tag of its own.
+        let mut rest = total.trim_start();
+        while let Some(tag) = rest.strip_prefix('<') {
+            rest = tag.split_once('>')?.1.trim_start();
+        }
+
+        RATIO_MARKERS
+            .iter()
+            .any(|marker| {
+                rest.get(..marker.len())
+                    .is_some_and(|word| word.eq_ignore_ascii_case(marker))
+            })
+            .then_some(detections)
+    })
+}
+
+/// Parse the digits `s` starts with.
+fn leading_number(s: &str) -> Option<u32> {
+    let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    s[..end].parse().ok()
+}
+
+/// Which tab of a mod's files page a file is listed under.
+///
+/// Ordered by priority, the same as those tabs: [`CategoryName::Main`] first, then the rest in
+/// declaration order, and categories unknown to this crate last.
+///
+/// # Examples
+///
+/// A category this crate doesn't know about is kept, and can be filtered on like any other:
+///
+/// ```
+/// # use cyclone_mod::request::CategoryName;
+/// let beta: CategoryName = serde_json::from_str(r#""BETA""#).unwrap();
+/// assert_eq!(beta, CategoryName::Other("BETA".into()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum CategoryName {
     Main,
@@ -602,10 +1611,13 @@ pub enum CategoryName {
     OldVersion,
     Miscellaneous,
     Archived,
+    /// A category this crate does not know about yet, exactly as Nexus sent it.
+    #[serde(untagged)]
+    Other(String),
 }
 
 impl CategoryName {
-    pub(crate) const fn to_header_str(self) -> &'static str {
+    pub(crate) fn to_header_str(&self) -> &str {
         match self {
             Self::Main => "main",
             Self::Update => "update",
@@ -613,20 +1625,23 @@ impl CategoryName {
             Self::OldVersion => "old_version",
             Self::Miscellaneous => "miscellaneous",
             Self::Archived => "archived",
+            Self::Other(raw) => raw,
         }
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct FileUpdate {
     old_file_id: u64,
     new_file_id: u64,
     old_file_name: String,
     new_file_name: String,
-    #[serde(with = "time::serde::timestamp")]
-    uploaded_timestamp: OffsetDateTime,
-    #[serde(with = "time::serde::iso8601")]
-    uploaded_time: OffsetDateTime,
+    #[serde(default, with = "ts")]
+    uploaded_timestamp: Option<OffsetDateTime>,
+    #[serde(default, with = "time::serde::iso8601::option")]
+    #[cfg_attr(not(feature = "serialize"), allow(dead_code))]
+    uploaded_time: Option<OffsetDateTime>,
 }
 
 impl FileUpdate {
@@ -640,18 +1655,49 @@ impl FileUpdate {
         (&self.old_file_name, &self.new_file_name)
     }
 
-    pub const fn uploaded_at(&self) -> UtcDateTime {
-        self.uploaded_timestamp.to_utc()
+    /// When the new file was uploaded, if Nexus knows.
+    pub const fn uploaded_at(&self) -> Option<UtcDateTime> {
+        match self.uploaded_timestamp {
+            Some(ts) => Some(ts.to_utc()),
+            None => None,
+        }
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// A place a file can be downloaded from.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct DownloadLink {
+    name: String,
+    short_name: String,
+    #[serde(rename = "URI")]
+    uri: Url,
+}
+
+impl DownloadLink {
+    /// Name of the CDN serving the file.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn short_name(&self) -> &str {
+        &self.short_name
+    }
+
+    pub const fn url(&self) -> &Url {
+        &self.uri
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct PreviewFileRoot {
     children: Vec<PreviewFileChildren>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(tag = "type")]
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[serde(tag = "type", from = "RawPreviewNode")]
 pub enum PreviewFileChildren {
     #[serde(rename = "directory")]
     Directory {
@@ -663,7 +1709,10 @@ pub enum PreviewFileChildren {
     File {
         path: String,
         name: String,
+        /// As Nexus wrote it, such as `1.4 MB`.
         size: String,
+        /// `size` parsed once up front, or [`None`] if it isn't in a format this crate knows.
+        size_bytes: Option<u64>,
     },
 }
 
@@ -673,6 +1722,90 @@ impl PreviewFileChildren {
             Self::File { path, .. } | Self::Directory { path, .. } => PathBuf::from(path),
         }
     }
+
+    /// Size of a file, or the total size of everything in a directory.
+    ///
+    /// Sizes in previews are rounded, so this is an estimate. [`None`] if any size couldn't be
+    /// parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclone_mod::request::PreviewFileChildren;
+    /// let dir: PreviewFileChildren = serde_json::from_str(r#"{
+    ///     "type": "directory", "path": "Data", "name": "Data", "children": [
+    ///         {"type": "file", "path": "Data/a.esp", "name": "a.esp", "size": "1.5 kB"},
+    ///         {"type": "file", "path": "Data/b.bsa", "name": "b.bsa", "size": "2 MB"}
+    ///     ]
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(dir.size_bytes(), Some(1536 + 2 * 1024 * 1024));
+    /// ```
+    pub fn size_bytes(&self) -> Option<u64> {
+        match self {
+            Self::File { size_bytes, .. } => *size_bytes,
+            Self::Directory { children, .. } => children.iter().map(Self::size_bytes).sum(),
+        }
+    }
+}
+
+/// [`PreviewFileChildren`] as Nexus sends it, before sizes are parsed.
+#[derive(Deserialize)]
+#[serde(tag = "type")]
+enum RawPreviewNode {
+    #[serde(rename = "directory")]
+    Directory {
+        path: String,
+        name: String,
+        children: Vec<PreviewFileChildren>,
+    },
+    #[serde(rename = "file")]
+    File {
+        path: String,
+        name: String,
+        size: String,
+    },
+}
+
+impl From<RawPreviewNode> for PreviewFileChildren {
+    fn from(raw: RawPreviewNode) -> Self {
+        match raw {
+            RawPreviewNode::Directory {
+                path,
+                name,
+                children,
+            } => Self::Directory {
+                path,
+                name,
+                children,
+            },
+            RawPreviewNode::File { path, name, size } => Self::File {
+                size_bytes: parse_size(&size),
+                path,
+                name,
+                size,
+            },
+        }
+    }
+}
+
+/// Parse a human readable size like `1.4 MB` into bytes, in powers of 1024 like
+/// [`ModFile::human_size`].
+fn parse_size(size: &str) -> Option<u64> {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+
+    let size = size.trim();
+    let split = size
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(size.len());
+    let (number, unit) = size.split_at(split);
+    let number: f64 = number.parse().ok()?;
+    let power = match unit.trim().to_ascii_uppercase().as_str() {
+        "" | "BYTES" => 0,
+        unit => UNITS.iter().position(|u| *u == unit)?,
+    };
+
+    Some((number * 1024f64.powi(power as i32)).round() as u64)
 }
 
 impl PreviewFileRoot {
@@ -701,13 +1834,14 @@ impl PreviewFileRoot {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct ModUpdated {
     mod_id: ModId,
-    #[serde(with = "time::serde::timestamp")]
-    latest_file_update: OffsetDateTime,
-    #[serde(with = "time::serde::timestamp")]
-    latest_mod_activity: OffsetDateTime,
+    #[serde(default, with = "ts")]
+    latest_file_update: Option<OffsetDateTime>,
+    #[serde(default, with = "ts")]
+    latest_mod_activity: Option<OffsetDateTime>,
 }
 
 impl ModUpdated {
@@ -715,13 +1849,116 @@ impl ModUpdated {
         self.mod_id
     }
 
-    pub const fn last_updated(&self) -> UtcDateTime {
-        self.latest_file_update.to_utc()
+    /// When a file was last uploaded or changed, `None` if the mod has no files yet.
+    pub const fn last_updated(&self) -> Option<UtcDateTime> {
+        match self.latest_file_update {
+            Some(ts) => Some(ts.to_utc()),
+            None => None,
+        }
+    }
+
+    pub const fn last_activity(&self) -> Option<UtcDateTime> {
+        match self.latest_mod_activity {
+            Some(ts) => Some(ts.to_utc()),
+            None => None,
+        }
     }
 
-    pub const fn last_activity(&self) -> UtcDateTime {
-        self.latest_mod_activity.to_utc()
+    /// Has a file been uploaded or changed after `when`?
+    pub fn has_new_files_since(&self, when: UtcDateTime) -> bool {
+        self.last_updated().is_some_and(|updated| updated > when)
     }
+
+    /// Was the most recent activity something other than a file change, such as a comment or an
+    /// endorsement?
+    ///
+    /// Such mods don't need their file list fetched again.
+    pub fn activity_only(&self) -> bool {
+        self.latest_mod_activity > self.latest_file_update
+    }
+}
+
+/// Drop the HTML tags from `html`, turning `<br>`s into newlines and decoding the common
+/// entities.
+///
+/// Text outside of tags is kept exactly as is, whatever its script.
+///
+/// # Examples
+///
+/// ```
+/// # use cyclone_mod::request::strip_html;
+/// assert_eq!(
+///     strip_html("<b>Привет</b>, <i>世界</i> 🌀<br />Tom &amp; Jerry &unknown;"),
+///     "Привет, 世界 🌀\nTom & Jerry &unknown;",
+/// );
+/// ```
+pub fn strip_html(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut chars = html.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '<' => {
+                let tag: String = chars.by_ref().take_while(|&c| c != '>').collect();
+                let name = tag.trim_matches('/').trim();
+                if name.eq_ignore_ascii_case("br") {
+                    text.push('\n');
+                }
+            }
+            '&' => {
+                let rest = chars.as_str();
+                let entity = rest.split_once(';').and_then(|(name, _)| {
+                    let decoded = match name {
+                        "amp" => '&',
+                        "lt" => '<',
+                        "gt" => '>',
+                        "quot" => '"',
+                        "#39" | "apos" => '\'',
+                        "nbsp" => ' ',
+                        _ => return None,
+                    };
+                    Some((decoded, name.len() + 1))
+                });
+                match entity {
+                    Some((decoded, len)) => {
+                        text.push(decoded);
+                        // `len` ends just past an ASCII `;`, so this is a char boundary.
+                        chars = rest[len..].chars();
+                    }
+                    None => text.push('&'),
+                }
+            }
+            c => text.push(c),
+        }
+    }
+
+    text
+}
+
+/// Split `updates` into mods in `known` and mods that are new to the caller, in that order.
+///
+/// # Examples
+///
+/// ```
+/// # use std::collections::HashSet;
+/// # use cyclone_mod::request::{ModId, ModUpdated, partition_updates};
+/// let updates: Vec<ModUpdated> = serde_json::from_str(r#"[
+///     { "mod_id": 1, "latest_file_update": 1700000000, "latest_mod_activity": 1700000000 },
+///     { "mod_id": 2, "latest_file_update": 1700000000, "latest_mod_activity": 1700000000 }
+/// ]"#).unwrap();
+/// let known: HashSet<ModId> = HashSet::from([serde_json::from_str("1").unwrap()]);
+///
+/// let (known, new) = partition_updates(updates, &known);
+/// assert_eq!(known[0].id(), 1);
+/// assert_eq!(new[0].id(), 2);
+/// ```
+pub fn partition_updates(
+    updates: Vec<ModUpdated>,
+    known: &HashSet<ModId>,
+) -> (Vec<ModUpdated>, Vec<ModUpdated>) {
+    updates
+        .into_iter()
+        .partition(|update| known.contains(&update.mod_id))
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -732,6 +1969,36 @@ pub enum TimePeriod {
 }
 
 impl TimePeriod {
+    /// The period that is exactly `days` long, if Nexus has one.
+    ///
+    /// Any length of a calendar month counts as a [`TimePeriod::Month`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclone_mod::request::TimePeriod;
+    /// assert!(TimePeriod::from_days(1) == Some(TimePeriod::Day));
+    /// assert!(TimePeriod::from_days(7) == Some(TimePeriod::Week));
+    /// assert!(TimePeriod::from_days(30) == Some(TimePeriod::Month));
+    /// assert!(TimePeriod::from_days(3).is_none());
+    /// ```
+    pub const fn from_days(days: u32) -> Option<Self> {
+        match days {
+            1 => Some(Self::Day),
+            7 => Some(Self::Week),
+            28..=31 => Some(Self::Month),
+            _ => None,
+        }
+    }
+
+    /// The smallest period spanning `window`, saturating at [`TimePeriod::Month`].
+    pub(crate) fn covering(window: Duration) -> Self {
+        [Self::Day, Self::Week]
+            .into_iter()
+            .find(|&period| window <= Into::<Duration>::into(period))
+            .unwrap_or(Self::Month)
+    }
+
     pub(crate) const fn as_str(&self) -> &str {
         match self {
             Self::Day => "1d",
@@ -741,18 +2008,29 @@ impl TimePeriod {
     }
 }
 
+/// The length of each period. A month is taken to be 31 days so that it covers every month.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use cyclone_mod::request::TimePeriod;
+/// let hours = |period: TimePeriod| Into::<Duration>::into(period).as_secs() / (60 * 60);
+/// assert_eq!(hours(TimePeriod::Day), 24);
+/// assert_eq!(hours(TimePeriod::Week), 24 * 7);
+/// assert!((24 * 30..=24 * 31).contains(&hours(TimePeriod::Month)));
+/// ```
 #[allow(clippy::from_over_into)]
 impl Into<Duration> for TimePeriod {
     fn into(self) -> Duration {
         match self {
-            Self::Day => Duration::from_hours(24),
-            Self::Week => Duration::from_hours(24 * 7),
-            Self::Month => Duration::from_hours(24 * 7 * 31),
+            Self::Day => Duration::from_secs(60 * 60 * 24),
+            Self::Week => Duration::from_secs(60 * 60 * 24 * 7),
+            Self::Month => Duration::from_secs(60 * 60 * 24 * 31),
         }
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[serde(transparent)]
 pub struct Changelog {
     logs: HashMap<String, Vec<String>>,
@@ -766,42 +2044,101 @@ impl Deref for Changelog {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl Changelog {
+    /// The changes made in `version`.
+    pub fn for_version(&self, version: &str) -> Option<&[String]> {
+        self.logs.get(version).map(Vec::as_slice)
+    }
+
+    /// The changes made in the newest version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclone_mod::request::Changelog;
+    /// let changelog: Changelog = serde_json::from_str(r#"{
+    ///     "1.9": ["Old"],
+    ///     "1.10": ["New"],
+    ///     "1.2": ["Older"]
+    /// }"#).unwrap();
+    /// assert_eq!(changelog.latest(), Some(("1.10", &["New".to_string()][..])));
+    /// ```
+    pub fn latest(&self) -> Option<(&str, &[String])> {
+        self.logs
+            .iter()
+            .max_by(|(a, _), (b, _)| compare_versions(a, b))
+            .map(|(version, changes)| (version.as_str(), changes.as_slice()))
+    }
+}
+
+/// Compare two mod versions, numerically part by part where possible, so `1.10` is newer than
+/// `1.9`.
+///
+/// Nexus versions are free text, so anything that doesn't look like a version falls back to
+/// comparing the strings.
+pub(crate) fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    fn parts(version: &str) -> Option<Vec<u64>> {
+        version
+            .trim()
+            .trim_start_matches(['v', 'V'])
+            .split(['.', '-', '+'])
+            .map(|part| part.parse().ok())
+            .collect()
+    }
+
+    match (parts(a), parts(b)) {
+        (Some(x), Some(y)) => x.cmp(&y).then_with(|| a.cmp(b)),
+        _ => a.cmp(b),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct GameMod {
     name: String,
     summary: String,
     description: String,
-    picture_url: Url,
+    #[serde(default, deserialize_with = "empty_url::deserialize")]
+    picture_url: Option<Url>,
     mod_downloads: u64,
     mod_unique_downloads: u64,
     uid: u64,
+    mod_id: ModId,
     game_id: u64,
     allow_rating: bool,
     domain_name: String,
     category_id: u64,
     version: String,
     endorsement_count: u64,
-    #[serde(with = "time::serde::timestamp")]
-    created_timestamp: OffsetDateTime,
-    #[serde(with = "time::serde::iso8601")]
-    created_time: OffsetDateTime,
-    #[serde(with = "time::serde::timestamp")]
-    updated_timestamp: OffsetDateTime,
-    #[serde(with = "time::serde::iso8601")]
-    updated_time: OffsetDateTime,
+    #[serde(default, with = "ts")]
+    created_timestamp: Option<OffsetDateTime>,
+    // The `_time` fields repeat the timestamps and are only read when serializing.
+    #[serde(default, with = "time::serde::iso8601::option")]
+    #[cfg_attr(not(feature = "serialize"), allow(dead_code))]
+    created_time: Option<OffsetDateTime>,
+    #[serde(default, with = "ts")]
+    updated_timestamp: Option<OffsetDateTime>,
+    #[serde(default, with = "time::serde::iso8601::option")]
+    #[cfg_attr(not(feature = "serialize"), allow(dead_code))]
+    updated_time: Option<OffsetDateTime>,
     author: String,
     uploaded_by: String,
     uploaded_users_profile_url: Url,
     contains_adult_content: bool,
-    // TODO: Make this an enum probably
-    status: String,
+    status: ModStatus,
     available: bool,
-    #[serde(skip)]
-    user: (),
+    #[serde(default)]
+    user: Option<UploaderMember>,
     endorsement: EndorsementInfo,
 }
 
 impl GameMod {
+    /// Parse a response saved from [`Api::mod_info`](`crate::Api::mod_info`), such as for testing
+    /// offline.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -814,8 +2151,20 @@ impl GameMod {
         &self.description
     }
 
-    pub const fn mod_picture(&self) -> &Url {
-        &self.picture_url
+    /// [`GameMod::description`] as plain text. See [`strip_html`].
+    pub fn description_text(&self) -> String {
+        strip_html(&self.description)
+    }
+
+    /// The mod's main picture, if it has one.
+    ///
+    /// Nexus sends either `null` or an empty string for mods without one.
+    pub const fn mod_picture(&self) -> Option<&Url> {
+        self.picture_url.as_ref()
+    }
+
+    pub const fn total_downloads(&self) -> u64 {
+        self.mod_downloads
     }
 
     pub const fn unique_downloads(&self) -> u64 {
@@ -826,6 +2175,10 @@ impl GameMod {
         self.uid
     }
 
+    pub const fn id(&self) -> ModId {
+        self.mod_id
+    }
+
     pub const fn game_id(&self) -> u64 {
         self.game_id
     }
@@ -838,10 +2191,26 @@ impl GameMod {
         &self.domain_name
     }
 
+    /// The game domain and mod ID, as taken by [`Api::mod_files`](`crate::Api::mod_files`) and
+    /// friends.
+    pub fn file_ref(&self) -> (String, ModId) {
+        (self.domain_name.clone(), self.mod_id)
+    }
+
     pub const fn category_id(&self) -> u64 {
         self.category_id
     }
 
+    /// Look up the name of [`GameMod::category_id`] among `game`'s categories.
+    ///
+    /// Not to be confused with [`CategoryName`], which categorizes the files of a mod.
+    pub fn category_name<'a>(&self, game: &'a GameId) -> Option<&'a str> {
+        game.categories()
+            .iter()
+            .find(|cat| cat.id() == self.category_id)
+            .map(GameCategory::name)
+    }
+
     pub fn version(&self) -> &str {
         &self.version
     }
@@ -850,12 +2219,24 @@ impl GameMod {
         self.endorsement_count
     }
 
-    pub const fn created_at(&self) -> UtcDateTime {
-        self.created_timestamp.to_utc()
+    /// When the mod was created.
+    ///
+    /// Mods still under moderation may not report this, and Nexus sends either `null` or `0`.
+    pub const fn created_at(&self) -> Option<UtcDateTime> {
+        match self.created_timestamp {
+            Some(ts) => Some(ts.to_utc()),
+            None => None,
+        }
     }
 
-    pub const fn updated_at(&self) -> UtcDateTime {
-        self.updated_timestamp.to_utc()
+    /// When the mod was last updated.
+    ///
+    /// Mods still under moderation may not report this.
+    pub const fn updated_at(&self) -> Option<UtcDateTime> {
+        match self.updated_timestamp {
+            Some(ts) => Some(ts.to_utc()),
+            None => None,
+        }
     }
 
     pub fn author(&self) -> &str {
@@ -870,6 +2251,17 @@ impl GameMod {
         &self.uploaded_users_profile_url
     }
 
+    /// Everything known about whoever uploaded the mod, such as for an "uploaded by" widget.
+    ///
+    /// This may differ from [`GameMod::author`], which is free text.
+    pub fn uploader(&self) -> Uploader<'_> {
+        Uploader {
+            name: &self.uploaded_by,
+            profile_url: &self.uploaded_users_profile_url,
+            member_id: self.uploader_member_id(),
+        }
+    }
+
     pub const fn adult_content(&self) -> bool {
         self.contains_adult_content
     }
@@ -878,16 +2270,287 @@ impl GameMod {
         self.available
     }
 
+    pub const fn status(&self) -> &ModStatus {
+        &self.status
+    }
+
+    /// Can the mod be downloaded and installed right now?
+    pub fn is_installable(&self) -> bool {
+        self.available && self.status == ModStatus::Published
+    }
+
+    /// [`GameMod::status`], [`GameMod::available`], and [`GameMod::adult_content`] rolled into
+    /// what a client should do with the mod.
+    pub fn visibility(&self) -> Visibility {
+        match self.status {
+            ModStatus::Removed | ModStatus::Wastebinned => Visibility::Removed,
+            ModStatus::Published if self.available && self.contains_adult_content => {
+                Visibility::AdultGated
+            }
+            ModStatus::Published if self.available => Visibility::Public,
+            _ => Visibility::Hidden,
+        }
+    }
+
     pub const fn endorsement(&self) -> &EndorsementInfo {
         &self.endorsement
     }
+
+    /// Member details of whoever uploaded the mod, if Nexus sent them. See also
+    /// [`GameMod::uploader`].
+    pub const fn uploader_member(&self) -> Option<&UploaderMember> {
+        self.user.as_ref()
+    }
+
+    /// Member ID of whoever uploaded the mod.
+    pub const fn uploader_member_id(&self) -> Option<u64> {
+        match &self.user {
+            Some(user) => Some(user.member_id),
+            None => None,
+        }
+    }
+
+    /// Is this mod among the user's `tracked` mods?
+    ///
+    /// The mod itself doesn't say, so this needs the list from
+    /// [`Api::tracked_mods`](`crate::Api::tracked_mods`).
+    pub fn is_tracked(&self, tracked: &TrackedMods) -> bool {
+        tracked
+            .get_game(&self.domain_name)
+            .is_some_and(|ids| ids.contains(&self.mod_id))
+    }
+}
+
+impl Display for GameMod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.name, self.version)
+    }
+}
+
+/// A match from [`Api::md5_search`](`crate::Api::md5_search`).
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct Md5Result {
+    #[serde(rename = "mod")]
+    game_mod: GameMod,
+    file_details: Md5FileDetails,
+}
+
+impl Md5Result {
+    /// The mod the matching file belongs to.
+    pub const fn game_mod(&self) -> &GameMod {
+        &self.game_mod
+    }
+
+    pub const fn file_details(&self) -> &Md5FileDetails {
+        &self.file_details
+    }
+
+    /// Verified by Nexus, since it came back from a hash lookup.
+    pub const fn mod_id(&self) -> ModId {
+        self.game_mod.mod_id
+    }
+
+    pub const fn file_id(&self) -> u64 {
+        self.file_details.file_id
+    }
+}
+
+/// The file half of an [`Md5Result`].
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct Md5FileDetails {
+    file_id: u64,
+    name: String,
+    #[serde(default)]
+    version: Option<String>,
+    file_name: String,
+    md5: String,
+    #[serde(default)]
+    size_in_bytes: Option<u64>,
+}
+
+impl Md5FileDetails {
+    pub const fn id(&self) -> u64 {
+        self.file_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    pub fn md5(&self) -> &str {
+        &self.md5
+    }
+
+    pub const fn size_bytes(&self) -> Option<u64> {
+        self.size_in_bytes
+    }
+}
+
+/// The few fields of a [`GameMod`] a list view needs.
+///
+/// Deserializing this skips over everything else, notably the description, which can be tens of
+/// kilobytes of HTML per mod.
+///
+/// # Examples
+///
+/// ```
+/// # use cyclone_mod::request::ModSummary;
+/// let summary = ModSummary::from_json(r#"{
+///     "name": "Some Mod",
+///     "summary": "Does things",
+///     "description": "<p>A very long description</p>",
+///     "mod_id": 1,
+///     "version": "1.0",
+///     "endorsement_count": 12,
+///     "updated_timestamp": 1700000000
+/// }"#).unwrap();
+/// assert_eq!(summary.name(), "Some Mod");
+/// assert_eq!(summary.endorsements(), 12);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct ModSummary {
+    mod_id: ModId,
+    name: String,
+    summary: String,
+    version: String,
+    endorsement_count: u64,
+    #[serde(default, with = "ts")]
+    updated_timestamp: Option<OffsetDateTime>,
+}
+
+impl ModSummary {
+    /// Parse a response saved from [`Api::mod_info`](`crate::Api::mod_info`), such as for testing
+    /// offline.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    pub const fn id(&self) -> ModId {
+        self.mod_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn summary(&self) -> &str {
+        &self.summary
+    }
+
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    pub const fn endorsements(&self) -> u64 {
+        self.endorsement_count
+    }
+
+    /// When the mod was last updated, see [`GameMod::updated_at`].
+    pub const fn updated_at(&self) -> Option<UtcDateTime> {
+        match self.updated_timestamp {
+            Some(ts) => Some(ts.to_utc()),
+            None => None,
+        }
+    }
+}
+
+/// See [`GameMod::uploader`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Uploader<'a> {
+    name: &'a str,
+    profile_url: &'a Url,
+    member_id: Option<u64>,
+}
+
+impl<'a> Uploader<'a> {
+    pub const fn name(&self) -> &'a str {
+        self.name
+    }
+
+    pub const fn profile_url(&self) -> &'a Url {
+        self.profile_url
+    }
+
+    /// [`None`] if Nexus didn't send the uploader's member details.
+    pub const fn member_id(&self) -> Option<u64> {
+        self.member_id
+    }
+}
+
+/// Moderation state of a [`GameMod`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[serde(rename_all = "snake_case")]
+pub enum ModStatus {
+    Published,
+    NotPublished,
+    /// Held back until the game it is for is published.
+    PublishWithGame,
+    Hidden,
+    UnderModeration,
+    Removed,
+    /// Deleted by its author.
+    Wastebinned,
+    /// A status this crate does not know about yet, exactly as Nexus sent it.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// See [`GameMod::visibility`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Visibility {
+    /// Anyone can see and download it.
+    Public,
+    /// Published, but only users who opted into adult content can see it.
+    AdultGated,
+    /// Exists, but is unpublished, hidden, or otherwise unavailable for now.
+    Hidden,
+    /// Gone for good.
+    Removed,
+}
+
+/// Member details of whoever uploaded a [`GameMod`], from its `user` object.
+///
+/// This describes the uploader, not the authenticated user. Nexus doesn't say whether the
+/// authenticated user tracks the mod; see [`GameMod::is_tracked`] for that.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct UploaderMember {
+    member_id: u64,
+    member_group_id: u64,
+    name: String,
+}
+
+impl UploaderMember {
+    pub const fn member_id(&self) -> u64 {
+        self.member_id
+    }
+
+    pub const fn member_group_id(&self) -> u64 {
+        self.member_group_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct EndorsementInfo {
     endorse_status: HasEndorsed,
-    #[serde(serialize_with = "ts::serialize")]
-    #[serde(deserialize_with = "ts::deserialize")]
+    #[serde(with = "ts")]
     timestamp: Option<OffsetDateTime>,
     version: Option<String>,
 }
@@ -901,6 +2564,18 @@ impl EndorsementInfo {
         matches!(self.endorse_status, HasEndorsed::Endorsed)
     }
 
+    /// When the user endorsed or abstained, if they have.
+    ///
+    /// A timestamp outside the range [`OffsetDateTime`] can represent is a deserialization error:
+    ///
+    /// ```
+    /// # use cyclone_mod::request::EndorsementInfo;
+    /// let body = format!(
+    ///     r#"{{"endorse_status": "Endorsed", "timestamp": {}, "version": null}}"#,
+    ///     i64::MAX,
+    /// );
+    /// assert!(serde_json::from_str::<EndorsementInfo>(&body).is_err());
+    /// ```
     pub const fn endorsed_at(&self) -> Option<OffsetDateTime> {
         self.timestamp
     }
@@ -910,19 +2585,27 @@ impl EndorsementInfo {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub enum HasEndorsed {
     Endorsed,
+    /// The user actively declined to endorse.
+    Abstained,
+    /// The user has neither endorsed nor abstained.
     Undecided,
 }
 
+/// Optional unix timestamps.
+///
+/// Nexus uses both `null` and `0` to mean "no timestamp", so both deserialize to [`None`].
 mod ts {
-    use serde::{Deserialize, Deserializer, Serializer};
+    use serde::{Deserialize, Deserializer, de};
     use time::OffsetDateTime;
 
+    #[cfg(feature = "serialize")]
     pub fn serialize<S>(value: &Option<OffsetDateTime>, s: S) -> Result<S::Ok, S::Error>
     where
-        S: Serializer,
+        S: serde::Serializer,
     {
         match value {
             Some(v) => s.serialize_i64(v.unix_timestamp()),
@@ -935,6 +2618,102 @@ mod ts {
         D: Deserializer<'de>,
     {
         let opt = Option::<i64>::deserialize(d)?;
-        Ok(opt.map(|secs| OffsetDateTime::from_unix_timestamp(secs).unwrap()))
+        opt.filter(|&secs| secs != 0)
+            .map(OffsetDateTime::from_unix_timestamp)
+            .transpose()
+            .map_err(de::Error::custom)
+    }
+}
+
+/// Fields Nexus may send as `null` when they are empty.
+mod null_as_default {
+    use serde::{Deserialize, Deserializer};
+
+    pub fn deserialize<'de, D, T>(d: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Default + Deserialize<'de>,
+    {
+        Ok(Option::<T>::deserialize(d)?.unwrap_or_default())
+    }
+}
+
+/// Optional URLs, where Nexus may send either `null` or `""` for "no URL".
+mod empty_url {
+    use reqwest::Url;
+    use serde::{Deserialize, Deserializer, de};
+
+    pub fn deserialize<'de, D>(d: D) -> Result<Option<Url>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let opt = Option::<String>::deserialize(d)?;
+        opt.filter(|url| !url.is_empty())
+            .map(|url| Url::parse(&url))
+            .transpose()
+            .map_err(de::Error::custom)
+    }
+}
+
+/// An RGB colour, as Nexus writes them in `#rrggbb` form.
+///
+/// # Examples
+///
+/// ```
+/// # use cyclone_mod::request::Rgb;
+/// assert_eq!(Rgb::from_hex("#da8e35").unwrap(), Rgb { r: 0xda, g: 0x8e, b: 0x35 });
+/// assert_eq!(Rgb::from_hex("#fff").unwrap(), Rgb { r: 0xff, g: 0xff, b: 0xff });
+/// assert_eq!(Rgb::from_hex("#abc").unwrap().to_string(), "#aabbcc");
+/// assert!(Rgb::from_hex("#abcd").is_err());
+/// assert!(Rgb::from_hex("#ggg").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    /// Parse a `#rgb` or `#rrggbb` colour. The leading `#` is optional.
+    pub fn from_hex(hex: &str) -> Result<Self, ColourError> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+
+        if let Some(c) = hex.chars().find(|c| !c.is_ascii_hexdigit()) {
+            return Err(ColourError::InvalidDigit(c));
+        }
+
+        let channel = |digits: &str| u8::from_str_radix(digits, 16).expect("checked above");
+
+        match hex.len() {
+            3 => {
+                let short = |i: usize| channel(&hex[i..=i]) * 0x11;
+                Ok(Self {
+                    r: short(0),
+                    g: short(1),
+                    b: short(2),
+                })
+            }
+            6 => Ok(Self {
+                r: channel(&hex[0..2]),
+                g: channel(&hex[2..4]),
+                b: channel(&hex[4..6]),
+            }),
+            len => Err(ColourError::InvalidLength(len)),
+        }
+    }
+}
+
+impl std::str::FromStr for Rgb {
+    type Err = ColourError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
+impl Display for Rgb {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
     }
 }
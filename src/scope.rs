@@ -0,0 +1,176 @@
+//! Requests bound to a single game, see [`GameScope`].
+
+use time::OffsetDateTime;
+
+use crate::{
+    Api,
+    err::{delete, get, post},
+    nxm::NxmParams,
+    request::{
+        CategoryName, Changelog, DownloadLink, FileStatus, GameDomain, GameId, GameMod, Md5Result,
+        ModFile, ModFiles, ModId, ModUpdated, TimePeriod,
+    },
+};
+
+/// An [`Api`] bound to one game, so the game doesn't have to be passed to every call.
+///
+/// Obtained through [`Api::scope`], or [`Api::default_scope`] for the game set with
+/// [`ApiBuilder::default_game`](`crate::ApiBuilder::default_game`). Every method forwards to the
+/// [`Api`] method of the same name.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use cyclone_mod::{Api, request::GameDomain};
+/// # async fn run(api: Api) -> Result<(), Box<dyn std::error::Error>> {
+/// let skyrim = api.scope(GameDomain::new("skyrimspecialedition")?);
+/// let id = skyrim.verify_mod(266).await?;
+/// let info = skyrim.mod_info(id).await?;
+/// let files = skyrim.mod_files(id, None).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct GameScope<'a> {
+    api: &'a Api,
+    game: GameDomain,
+}
+
+impl<'a> GameScope<'a> {
+    pub(crate) const fn new(api: &'a Api, game: GameDomain) -> Self {
+        Self { api, game }
+    }
+
+    /// The game requests are made about.
+    pub const fn domain(&self) -> &GameDomain {
+        &self.game
+    }
+
+    /// The [`Api`] this scope makes its requests through.
+    pub const fn api(&self) -> &'a Api {
+        self.api
+    }
+
+    pub async fn game(&self) -> Result<GameId, get::GameModError> {
+        self.api.game(&self.game).await
+    }
+
+    pub async fn track_mod<T: Into<u64>>(
+        &self,
+        id: T,
+    ) -> Result<post::PostModStatus, post::TrackModError> {
+        self.api.track_mod(&self.game, id).await
+    }
+
+    pub async fn untrack_mod<T: Into<ModId>>(
+        &self,
+        id: T,
+    ) -> Result<delete::UntrackStatus, delete::DeleteModError> {
+        self.api.untrack_mod(&self.game, id).await
+    }
+
+    pub async fn updated_during(
+        &self,
+        time: TimePeriod,
+    ) -> Result<Vec<ModUpdated>, get::GameModError> {
+        self.api.updated_during(&self.game, time).await
+    }
+
+    pub async fn updated_since(
+        &self,
+        since: OffsetDateTime,
+    ) -> Result<Vec<ModUpdated>, get::GameModError> {
+        self.api.updated_since(&self.game, since).await
+    }
+
+    pub async fn changelogs<T: Into<ModId>>(&self, id: T) -> Result<Changelog, get::GameModError> {
+        self.api.changelogs(&self.game, id).await
+    }
+
+    pub async fn latest_added(&self) -> Result<Vec<GameMod>, get::GameModError> {
+        self.api.latest_added(&self.game).await
+    }
+
+    pub async fn latest_updated(&self) -> Result<Vec<GameMod>, get::GameModError> {
+        self.api.latest_updated(&self.game).await
+    }
+
+    pub async fn trending(&self) -> Result<Vec<GameMod>, get::GameModError> {
+        self.api.trending(&self.game).await
+    }
+
+    pub async fn mod_info<T: Into<ModId>>(&self, id: T) -> Result<GameMod, get::GameModError> {
+        self.api.mod_info(&self.game, id).await
+    }
+
+    pub async fn verify_mod(&self, id: u64) -> Result<ModId, get::GameModError> {
+        self.api.verify_mod(&self.game, id).await
+    }
+
+    pub async fn md5_search(&self, md5: &str) -> Result<Vec<Md5Result>, get::GameModError> {
+        self.api.md5_search(&self.game, md5).await
+    }
+
+    pub async fn endorse<T: Into<ModId>>(
+        &self,
+        id: T,
+        version: Option<&str>,
+    ) -> Result<(), post::EndorseError> {
+        self.api.endorse(&self.game, id, version).await
+    }
+
+    pub async fn abstain<T: Into<ModId>>(
+        &self,
+        id: T,
+        version: Option<&str>,
+    ) -> Result<(), post::EndorseError> {
+        self.api.abstain(&self.game, id, version).await
+    }
+
+    pub async fn mod_files<S: Into<ModId>>(
+        &self,
+        mod_id: S,
+        category: Option<CategoryName>,
+    ) -> Result<ModFiles, get::GameModError> {
+        self.api.mod_files(&self.game, mod_id, category).await
+    }
+
+    pub async fn file_update_status<S: Into<ModId>>(
+        &self,
+        mod_id: S,
+        file_id: u64,
+    ) -> Result<FileStatus, get::GameModError> {
+        self.api
+            .file_update_status(&self.game, mod_id, file_id)
+            .await
+    }
+
+    pub async fn mod_file<S: Into<ModId>>(
+        &self,
+        mod_id: S,
+        file_id: u64,
+    ) -> Result<ModFile, get::GameModError> {
+        self.api.mod_file(&self.game, mod_id, file_id).await
+    }
+
+    pub async fn download_link<S: Into<ModId>>(
+        &self,
+        mod_id: S,
+        file_id: u64,
+        nxm: Option<NxmParams>,
+    ) -> Result<Vec<DownloadLink>, get::GameModError> {
+        self.api
+            .download_link(&self.game, mod_id, file_id, nxm)
+            .await
+    }
+
+    pub async fn primary_download_link<S: Into<ModId>>(
+        &self,
+        mod_id: S,
+        nxm: Option<NxmParams>,
+    ) -> Result<Vec<DownloadLink>, get::GameModError> {
+        self.api
+            .primary_download_link(&self.game, mod_id, nxm)
+            .await
+    }
+}
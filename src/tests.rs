@@ -0,0 +1,421 @@
+//! Tests that go through [`Api`] against a local stand-in for Nexus, and tests that need whole
+//! response bodies.
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    thread::{self, JoinHandle},
+};
+
+use crate::{
+    Api,
+    err::{StatusKind, get::GameModError},
+    request::{
+        CategoryName, GameId, GameMod, HasEndorsed, ModFile, ModFiles, ModId, ModUpdated,
+        TrackedMods, TrackedModsRaw,
+    },
+};
+
+const GAME: &str = r#"{
+    "id": 110, "name": "Skyrim", "forum_url": "https://forums.nexusmods.com",
+    "nexusmods_url": "https://www.nexusmods.com/skyrim", "genre": "RPG", "file_count": 1,
+    "domain_name": "skyrim", "approved_date": 1, "file_views": 1, "authors": 1,
+    "file_endorsements": 1, "mods": 1, "categories": []
+}"#;
+
+/// A mod under moderation, which has neither a picture nor a creation time yet.
+const GAME_MOD: &str = r#"{
+    "name": "Some Mod", "summary": "", "description": "", "picture_url": null,
+    "mod_downloads": 0, "mod_unique_downloads": 0, "uid": 1, "mod_id": 1, "game_id": 1704,
+    "allow_rating": true, "domain_name": "skyrimspecialedition", "category_id": 1,
+    "version": "1.0", "endorsement_count": 0, "created_timestamp": null, "author": "Someone",
+    "uploaded_by": "Someone", "uploaded_users_profile_url": "https://www.nexusmods.com/users/1",
+    "contains_adult_content": false, "status": "under_moderation", "available": false,
+    "endorsement": { "endorse_status": "Undecided", "timestamp": null, "version": null }
+}"#;
+
+fn mod_file(size_in_bytes: u64, preview: &str) -> ModFile {
+    serde_json::from_str(&format!(
+        r#"{{
+            "id": [1, 110], "uid": 1, "file_id": 1, "name": "Main", "version": "1.0",
+            "category_id": 1, "category_name": "MAIN", "is_primary": true, "size": 1,
+            "file_name": "main.7z", "mod_version": "1.0", "external_virus_scan_url": null,
+            "description": null, "size_kb": 1, "size_in_bytes": {size_in_bytes},
+            "changelog_html": null, "content_preview_link": "{preview}"
+        }}"#
+    ))
+    .unwrap()
+}
+
+/// What [`MockServer`] answers a request with.
+struct Reply {
+    status: &'static str,
+    headers: Vec<(&'static str, &'static str)>,
+    body: String,
+    /// Run once the request has arrived, before it is answered.
+    before: Option<Box<dyn FnOnce() + Send>>,
+}
+
+impl Reply {
+    fn new(status: &'static str, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            headers: vec![],
+            body: body.into(),
+            before: None,
+        }
+    }
+
+    fn ok(body: impl Into<String>) -> Self {
+        Self::new("200 OK", body)
+    }
+
+    fn header(mut self, name: &'static str, value: &'static str) -> Self {
+        self.headers.push((name, value));
+        self
+    }
+
+    fn before(mut self, f: impl FnOnce() + Send + 'static) -> Self {
+        self.before = Some(Box::new(f));
+        self
+    }
+}
+
+/// A request as [`MockServer`] received it.
+struct Request {
+    /// Such as `GET /v1/games.json HTTP/1.1`.
+    line: String,
+    /// Lowercased, one `name: value` per line.
+    headers: String,
+}
+
+/// A local HTTP server answering one connection per [`Reply`], in order.
+struct MockServer {
+    listener: TcpListener,
+}
+
+impl MockServer {
+    fn new() -> Self {
+        Self {
+            listener: TcpListener::bind("127.0.0.1:0").unwrap(),
+        }
+    }
+
+    fn url(&self) -> String {
+        format!("http://{}", self.listener.local_addr().unwrap())
+    }
+
+    /// An [`Api`] sending its requests here.
+    fn api(&self) -> Api {
+        Api::builder("secret-key")
+            .base_url(&self.url())
+            .build()
+            .unwrap()
+    }
+
+    /// Answer `replies` on another thread. Joining it hands back every request received.
+    fn serve(self, replies: Vec<Reply>) -> JoinHandle<Vec<Request>> {
+        thread::spawn(move || {
+            replies
+                .into_iter()
+                .map(|reply| {
+                    let (stream, _) = self.listener.accept().unwrap();
+                    let mut stream = BufReader::new(stream);
+
+                    let mut line = String::new();
+                    stream.read_line(&mut line).unwrap();
+                    let mut headers = String::new();
+                    let mut length = 0;
+                    loop {
+                        let mut header = String::new();
+                        stream.read_line(&mut header).unwrap();
+                        let header = header.trim_end().to_ascii_lowercase();
+                        if header.is_empty() {
+                            break;
+                        }
+                        if let Some(value) = header.strip_prefix("content-length:") {
+                            length = value.trim().parse().unwrap();
+                        }
+                        headers.push_str(&header);
+                        headers.push('\n');
+                    }
+                    stream.read_exact(&mut vec![0; length]).unwrap();
+
+                    if let Some(before) = reply.before {
+                        before();
+                    }
+
+                    let stream = stream.get_mut();
+                    write!(stream, "HTTP/1.1 {}\r\n", reply.status).unwrap();
+                    for (name, value) in reply.headers {
+                        write!(stream, "{name}: {value}\r\n").unwrap();
+                    }
+                    write!(
+                        stream,
+                        "connection: close\r\ncontent-length: {}\r\n\r\n{}",
+                        reply.body.len(),
+                        reply.body,
+                    )
+                    .unwrap();
+
+                    Request {
+                        line: line.trim_end().to_string(),
+                        headers,
+                    }
+                })
+                .collect()
+        })
+    }
+}
+
+#[tokio::test]
+async fn not_modified_reuses_the_cached_game() {
+    let server = MockServer::new();
+    let api = server.api();
+    let requests = server.serve(vec![
+        Reply::ok(GAME)
+            .header("etag", "\"v1\"")
+            .header("cache-control", "no-cache"),
+        Reply::new("304 Not Modified", "").header("etag", "\"v1\""),
+    ]);
+
+    let first = api.game("skyrim").await.unwrap();
+    let second = api.game("skyrim").await.unwrap();
+    assert_eq!(first, second);
+
+    let requests = requests.join().unwrap();
+    assert!(!requests[0].headers.contains("if-none-match"));
+    assert!(requests[1].headers.contains("if-none-match: \"v1\""));
+}
+
+#[tokio::test]
+async fn clearing_the_cache_mid_revalidation_resends_the_request() {
+    let server = MockServer::new();
+    let api = server.api();
+    let clearing = api.clone();
+    let requests = server.serve(vec![
+        Reply::ok(GAME)
+            .header("etag", "\"v1\"")
+            .header("cache-control", "no-cache"),
+        Reply::new("304 Not Modified", "")
+            .header("etag", "\"v1\"")
+            .before(move || clearing.clear_cache()),
+        Reply::ok(GAME),
+    ]);
+
+    api.game("skyrim").await.unwrap();
+    assert_eq!(api.game("skyrim").await.unwrap().pretty_name(), "Skyrim");
+
+    let requests = requests.join().unwrap();
+    assert!(requests[1].headers.contains("if-none-match"));
+    assert!(!requests[2].headers.contains("if-none-match"));
+}
+
+#[tokio::test]
+async fn endorsement_state_confirms_an_abstain() {
+    let state = |status: &str| {
+        format!(
+            r#"{{"endorsement": {{"endorse_status": "{status}", "timestamp": null, "version": null}}}}"#
+        )
+    };
+    let server = MockServer::new();
+    let api = server.api();
+    let requests = server.serve(vec![
+        Reply::ok(state("Undecided")),
+        Reply::ok("{}"),
+        Reply::ok(state("Abstained")),
+    ]);
+
+    let id = ModId::from_u64(3863);
+    assert_eq!(
+        api.endorsement_state("skyrim", id).await.unwrap(),
+        HasEndorsed::Undecided,
+    );
+    api.abstain("skyrim", id, None).await.unwrap();
+    assert_eq!(
+        api.endorsement_state("skyrim", id).await.unwrap(),
+        HasEndorsed::Abstained,
+    );
+
+    let lines = requests
+        .join()
+        .unwrap()
+        .into_iter()
+        .map(|request| request.line)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        lines,
+        [
+            "GET /v1/games/skyrim/mods/3863.json HTTP/1.1",
+            "POST /v1/games/skyrim/mods/3863/abstain.json HTTP/1.1",
+            "GET /v1/games/skyrim/mods/3863.json HTTP/1.1",
+        ],
+    );
+}
+
+#[tokio::test]
+async fn games_filtered_sends_include_unapproved() {
+    let server = MockServer::new();
+    let api = server.api();
+    let requests = server.serve(vec![Reply::ok("[]")]);
+
+    assert!(api.games_filtered(true).await.unwrap().is_empty());
+    assert_eq!(
+        requests.join().unwrap()[0].line,
+        "GET /v1/games.json?include_unapproved=true HTTP/1.1",
+    );
+}
+
+#[tokio::test]
+async fn unknown_categories_are_sent_back_as_is() {
+    let beta: CategoryName = serde_json::from_str(r#""BETA""#).unwrap();
+    assert_eq!(beta, CategoryName::Other("BETA".into()));
+
+    let server = MockServer::new();
+    let api = server.api();
+    let requests = server.serve(vec![Reply::ok(r#"{"files": [], "file_updates": []}"#)]);
+
+    api.mod_files("skyrim", ModId::from_u64(3863), Some(beta))
+        .await
+        .unwrap();
+    assert_eq!(
+        requests.join().unwrap()[0].line,
+        "GET /v1/games/skyrim/mods/3863/files.json?category=BETA HTTP/1.1",
+    );
+}
+
+#[tokio::test]
+async fn adult_content_is_told_apart_from_missing_mods() {
+    let adult = || {
+        Reply::new(
+            "403 Forbidden",
+            r#"{"code": 403, "message": "This mod contains adult content and you have chosen to hide it"}"#,
+        )
+    };
+    let server = MockServer::new();
+    let api = server.api();
+    let requests = server.serve(vec![
+        adult(),
+        Reply::new(
+            "404 Not Found",
+            r#"{"code": 404, "message": "No Mod Found"}"#,
+        ),
+        adult(),
+        adult(),
+    ]);
+
+    let id = ModId::from_u64(3863);
+    let blocked = api.mod_info("skyrim", id).await.unwrap_err();
+    assert!(matches!(blocked, GameModError::AdultContentBlocked(_)));
+    assert_eq!(blocked.kind(), StatusKind::Auth);
+    let missing = api.mod_info("skyrim", id).await.unwrap_err();
+    assert!(matches!(missing, GameModError::NotFound { .. }));
+    assert_eq!(missing.kind(), StatusKind::NotFound);
+    assert!(matches!(
+        api.mod_files("skyrim", id, None).await,
+        Err(GameModError::AdultContentBlocked(_)),
+    ));
+    assert!(matches!(
+        api.changelogs("skyrim", id).await,
+        Err(GameModError::AdultContentBlocked(_)),
+    ));
+    requests.join().unwrap();
+}
+
+#[tokio::test]
+async fn previews_are_fetched_without_the_api_key() {
+    let server = MockServer::new();
+    let file = mod_file(1024, &format!("{}/preview.json", server.url()));
+    let api = Api::new("secret-key");
+    let requests = server.serve(vec![Reply::ok(r#"{"children": []}"#)]);
+
+    assert!(api.preview_of(&file).await.unwrap().files().is_empty());
+
+    let request = &requests.join().unwrap()[0];
+    assert!(!request.headers.contains("apikey"));
+    assert!(!request.headers.contains("secret-key"));
+    assert!(!request.headers.contains("application/json"));
+    // Nor does it count as a response from Nexus.
+    assert!(api.last_response_meta().is_none());
+}
+
+#[test]
+fn created_at_is_none_for_null_and_zero() {
+    assert_eq!(GameMod::from_json(GAME_MOD).unwrap().created_at(), None);
+
+    let zero = GAME_MOD.replace(r#""created_timestamp": null"#, r#""created_timestamp": 0"#);
+    assert_eq!(GameMod::from_json(&zero).unwrap().created_at(), None);
+}
+
+#[test]
+fn update_times_are_none_for_null() {
+    let files = ModFiles::from_json(
+        r#"{"files": [], "file_updates": [{
+            "old_file_id": 1, "new_file_id": 2, "old_file_name": "a", "new_file_name": "b",
+            "uploaded_timestamp": null, "uploaded_time": null
+        }]}"#,
+    )
+    .unwrap();
+    assert_eq!(files.iter_updates().next().unwrap().uploaded_at(), None);
+
+    let updated: ModUpdated = serde_json::from_str(
+        r#"{"mod_id": 1, "latest_file_update": null, "latest_mod_activity": 1700000000}"#,
+    )
+    .unwrap();
+    assert_eq!(updated.last_updated(), None);
+    assert!(!updated.has_new_files_since(time::UtcDateTime::UNIX_EPOCH));
+    assert!(updated.activity_only());
+}
+
+#[test]
+fn is_tracked_matches_game_and_id() {
+    let tracked =
+        |entries: &str| TrackedMods::from(serde_json::from_str::<TrackedModsRaw>(entries).unwrap());
+    let game_mod = GameMod::from_json(GAME_MOD).unwrap();
+
+    assert!(game_mod.is_tracked(&tracked(
+        r#"[{"mod_id": 1, "domain_name": "skyrimspecialedition"}]"#
+    )));
+    assert!(!game_mod.is_tracked(&tracked(r#"[{"mod_id": 1, "domain_name": "skyrim"}]"#)));
+    assert!(!game_mod.is_tracked(&tracked(
+        r#"[{"mod_id": 2, "domain_name": "skyrimspecialedition"}]"#
+    )));
+}
+
+#[test]
+fn mod_picture_is_none_for_null_and_empty() {
+    assert_eq!(GameMod::from_json(GAME_MOD).unwrap().mod_picture(), None);
+
+    let empty = GAME_MOD.replace(r#""picture_url": null"#, r#""picture_url": """#);
+    assert_eq!(GameMod::from_json(&empty).unwrap().mod_picture(), None);
+}
+
+#[test]
+fn human_size_picks_the_largest_unit() {
+    for (bytes, human) in [
+        (0, "0 B"),
+        (1023, "1023 B"),
+        (1024, "1.00 KB"),
+        (1536, "1.50 KB"),
+        (1048576, "1.00 MB"),
+        (1288490189, "1.20 GB"),
+    ] {
+        assert_eq!(mod_file(bytes, "https://example.com").human_size(), human);
+    }
+}
+
+#[test]
+fn unapproved_games_without_categories() {
+    let game = |categories: &str| {
+        GameId::from_json(&GAME.replace(r#""categories": []"#, categories)).unwrap()
+    };
+
+    for game in [game(r#""categories": null"#), game(r#""no_categories": 0"#)] {
+        assert!(game.categories().is_empty());
+        assert_eq!(game.root_categories().count(), 0);
+    }
+
+    let unapproved =
+        GameId::from_json(&GAME.replace(r#""approved_date": 1"#, r#""approved_date": 0"#));
+    assert!(!unapproved.unwrap().is_approved());
+}
@@ -0,0 +1,61 @@
+//! Client-side request throttling shared by every clone of an [`Api`](`crate::Api`).
+
+use std::{sync::Mutex, time::Duration};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+use futures_timer::Delay;
+
+/// A token bucket allowing `rate` requests per second, with bursts of up to `rate` requests.
+pub(crate) struct Throttle {
+    rate: f64,
+    state: Mutex<State>,
+}
+
+struct State {
+    /// Negative when requests are queued up waiting for tokens.
+    tokens: f64,
+    refilled: Instant,
+}
+
+impl Throttle {
+    /// `rate` must be positive and finite.
+    pub(crate) fn new(rate: f64) -> Self {
+        Self {
+            rate,
+            state: Mutex::new(State {
+                tokens: rate.max(1.0),
+                refilled: Instant::now(),
+            }),
+        }
+    }
+
+    pub(crate) const fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Wait until a request may be sent.
+    ///
+    /// Callers are served in the order they arrive.
+    pub(crate) async fn acquire(&self) {
+        let wait = {
+            let mut state = self.state.lock().unwrap();
+            let now = Instant::now();
+            let refill = now.duration_since(state.refilled).as_secs_f64() * self.rate;
+            state.tokens = (state.tokens + refill).min(self.rate.max(1.0));
+            state.refilled = now;
+
+            // Take the token now, even if that means going into debt, so later callers queue up
+            // behind this one.
+            state.tokens -= 1.0;
+            (state.tokens < 0.0).then(|| Duration::from_secs_f64(-state.tokens / self.rate))
+        };
+
+        if let Some(wait) = wait {
+            Delay::new(wait).await;
+        }
+    }
+}